@@ -1,20 +1,43 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Mutex;
 use std::time::Instant;
 
+use tracing::debug;
+use tracing::info;
 use tracing::warn;
 
 use crate::config::Config;
+use crate::metrics::CommandErrorSnapshot;
 use crate::metrics::DeviceSnapshot;
+use crate::metrics::EnduranceGroupSnapshot;
 use crate::metrics::ErrorLogSnapshot;
+use crate::metrics::FeatureSnapshot;
 use crate::metrics::NamespaceSnapshot;
+use crate::metrics::PersistentEventLogSnapshot;
 use crate::metrics::ScrapeReport;
+use crate::metrics::SelfTestScheduleSnapshot;
 use crate::metrics::SelfTestSnapshot;
+use crate::metrics::TemperatureThresholdSnapshot;
+use crate::metrics::VendorLogSnapshot;
+use crate::metrics::ZonedNamespaceSnapshot;
 use crate::nvme::device::NvmeDevice;
 use crate::nvme::discovery;
 use crate::nvme::discovery::NvmeController;
+use crate::nvme::discovery::ReconciledNamespace;
 use crate::nvme::error::NvmeError;
+use crate::nvme::ioctl::FeatureSelect;
+use crate::nvme::types::PersistentEvent;
+use crate::nvme::types::SmartLog;
+use crate::nvme::types::ZoneState;
+use crate::nvme::types::COMMAND_SET_ZONED;
+use crate::nvme::vendor::custom::CustomLogSnapshot;
+use crate::nvme::vendor::intel::IntelSmartLog;
+use crate::nvme::vendor::intel::LID_INTEL_SMART;
+use crate::nvme::vendor::ocp::LID_OCP_LATENCY_MONITOR;
+use crate::nvme::vendor::ocp::LID_OCP_SMART;
 
 pub struct NvmeCollector {
     config: Config,
@@ -24,6 +47,59 @@ pub struct NvmeCollector {
 struct CollectorState {
     discovery_cache: Option<CachedDiscovery>,
     devices: HashMap<String, CachedDevice>,
+    command_errors: BTreeMap<CommandErrorKey, u64>,
+    self_test_outcomes: BTreeMap<SelfTestOutcomeKey, u64>,
+    vendor_log_support: BTreeMap<VendorLogKey, bool>,
+    namespace_mismatches: BTreeSet<String>,
+    persistent_events: HashMap<String, PersistentEventTracker>,
+    self_test_failures: HashMap<String, SelfTestFailureTracker>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct PersistentEventTracker {
+    event_totals: BTreeMap<String, u64>,
+    marker: Option<PersistentEventMarker>,
+}
+
+#[derive(Clone, Debug)]
+struct PersistentEventMarker {
+    generation_number: u16,
+    newest: Option<PersistentEvent>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct SelfTestFailureTracker {
+    failure_totals: BTreeMap<String, u64>,
+    seen: BTreeSet<SelfTestFailureKey>,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct SelfTestFailureKey {
+    operation: u8,
+    result: u8,
+    segment_number: u8,
+    power_on_hours: u64,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct VendorLogKey {
+    device: String,
+    lid: u8,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct CommandErrorKey {
+    device: String,
+    opcode: u8,
+    lid: Option<u8>,
+    status: String,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct SelfTestOutcomeKey {
+    device: String,
+    test_type: String,
+    outcome: String,
 }
 
 #[derive(Clone)]
@@ -45,6 +121,12 @@ impl NvmeCollector {
             state: Mutex::new(CollectorState {
                 discovery_cache: None,
                 devices: HashMap::new(),
+                command_errors: BTreeMap::new(),
+                self_test_outcomes: BTreeMap::new(),
+                vendor_log_support: BTreeMap::new(),
+                namespace_mismatches: BTreeSet::new(),
+                persistent_events: HashMap::new(),
+                self_test_failures: HashMap::new(),
             }),
         }
     }
@@ -89,11 +171,19 @@ impl NvmeCollector {
 
         for controller in &controllers {
             match self.collect_controller(controller) {
-                Ok(snapshot) => {
+                Ok(mut snapshot) => {
+                    log_new_error_entries(
+                        controller,
+                        previous_devices.get(&controller.name),
+                        &snapshot,
+                    );
+                    self.track_persistent_events(controller, &mut snapshot);
+                    self.track_self_test_failures(controller, &mut snapshot);
                     collected_devices.insert(controller.name.clone(), snapshot);
                 }
                 Err(error) => {
                     scrape_success = false;
+                    self.record_command_error(controller, &error);
                     warn!(
                         controller = %controller.name,
                         device = %controller.dev_path.display(),
@@ -114,28 +204,40 @@ impl NvmeCollector {
         }
 
         let snapshots = self.merge_device_state(now, &discovered_names, collected_devices)?;
+        let command_errors = self.command_error_snapshots()?;
+        let self_test_schedule = self.self_test_schedule_snapshots()?;
 
         let report = ScrapeReport {
             duration_seconds: started_at.elapsed().as_secs_f64(),
             success: scrape_success,
             discovered_device_count: controllers.len(),
             devices: snapshots,
+            command_errors,
+            kernel_default_ps_max_latency_us: discovery::kernel_default_ps_max_latency_us(),
+            self_test_schedule,
             collect_namespace: self.config.collect_namespace,
             collect_error_log: self.config.collect_error_log,
             collect_self_test: self.config.collect_self_test,
+            collect_features: self.config.collect_features,
+            collect_vendor_logs: self.config.collect_vendor_logs,
+            collect_persistent_event_log: self.config.collect_persistent_event_log,
         };
 
         crate::metrics::encode_report(&report)
     }
 
     fn collect_controller(&self, controller: &NvmeController) -> Result<DeviceSnapshot, NvmeError> {
-        let device = NvmeDevice::open(&controller.dev_path)?;
+        let mut device = NvmeDevice::open(&controller.dev_path)?;
         let timeout_ms = u32::try_from(self.config.ioctl_timeout.as_millis())
             .map_err(|_| NvmeError::Parse("ioctl timeout exceeds u32".to_string()))?;
 
         let identify = match device.identify_controller(timeout_ms) {
-            Ok(value) => Some(value),
+            Ok(value) => {
+                device.set_max_transfer(&value);
+                Some(value)
+            }
             Err(error) => {
+                self.record_command_error(controller, &error);
                 warn!(
                     controller = %controller.name,
                     error = %error,
@@ -167,20 +269,82 @@ impl NvmeCollector {
 
         let mut namespaces = Vec::<NamespaceSnapshot>::new();
         if self.config.collect_namespace {
-            for namespace in &controller.namespaces {
+            let active_nsids = self.optional(
+                controller,
+                "active namespace list",
+                device.active_namespace_ids(timeout_ms),
+            );
+            let supports_namespace_smart = identify
+                .as_ref()
+                .is_some_and(|value| value.supports_per_namespace_smart());
+            let supports_descriptors = identify
+                .as_ref()
+                .is_some_and(|value| value.version_at_least(1, 3));
+            let reconciled = discovery::reconcile_namespaces(
+                &controller.name,
+                &controller.namespaces,
+                active_nsids.as_deref(),
+            );
+
+            for namespace in &reconciled {
+                let new_mismatch = self.namespace_mismatch_is_new(namespace);
+                if new_mismatch && !namespace.active {
+                    warn!(
+                        controller = %controller.name,
+                        namespace = %namespace.name,
+                        "namespace has a block device but is not reported active by the controller"
+                    );
+                } else if new_mismatch {
+                    debug!(
+                        controller = %controller.name,
+                        namespace = %namespace.name,
+                        "active namespace has no block device"
+                    );
+                }
+
+                let descriptors = if supports_descriptors && namespace.active {
+                    self.optional(
+                        controller,
+                        "namespace identification descriptors",
+                        device.namespace_descriptors(namespace.nsid, timeout_ms),
+                    )
+                } else {
+                    None
+                };
+
                 match device.identify_namespace(namespace.nsid, timeout_ms) {
                     Ok(identify_namespace) => namespaces.push(NamespaceSnapshot {
                         namespace: namespace.name.clone(),
-                        nsze: identify_namespace.nsze,
-                        ncap: identify_namespace.ncap,
-                        nuse: identify_namespace.nuse,
+                        nsid: namespace.nsid,
+                        active: namespace.active,
+                        block_device: namespace.block_device,
+                        identify: identify_namespace,
+                        smart: if supports_namespace_smart && namespace.active {
+                            self.optional(
+                                controller,
+                                "namespace smart log",
+                                device.namespace_smart_log(namespace.nsid, timeout_ms),
+                            )
+                        } else {
+                            None
+                        },
+                        zoned: if descriptors.and_then(|value| value.csi) == Some(COMMAND_SET_ZONED)
+                        {
+                            self.collect_zoned_namespace(controller, &device, namespace, timeout_ms)
+                        } else {
+                            None
+                        },
+                        descriptors,
                     }),
-                    Err(error) => warn!(
-                        controller = %controller.name,
-                        namespace = %namespace.name,
-                        error = %error,
-                        "identify namespace failed"
-                    ),
+                    Err(error) => {
+                        self.record_command_error(controller, &error);
+                        warn!(
+                            controller = %controller.name,
+                            namespace = %namespace.name,
+                            error = %error,
+                            "identify namespace failed"
+                        );
+                    }
                 }
             }
         }
@@ -190,8 +354,10 @@ impl NvmeCollector {
                 Ok(value) => Some(ErrorLogSnapshot {
                     non_zero_entries: value.non_zero_entries,
                     max_error_count: value.max_error_count,
+                    entries: value.entries,
                 }),
                 Err(error) => {
+                    self.record_command_error(controller, &error);
                     warn!(
                         controller = %controller.name,
                         error = %error,
@@ -209,8 +375,11 @@ impl NvmeCollector {
                 Ok(value) => Some(SelfTestSnapshot {
                     current_operation: value.current_operation,
                     current_completion_ratio: value.current_completion_ratio,
+                    results: value.results,
+                    failure_totals: BTreeMap::new(),
                 }),
                 Err(error) => {
+                    self.record_command_error(controller, &error);
                     warn!(
                         controller = %controller.name,
                         error = %error,
@@ -223,19 +392,394 @@ impl NvmeCollector {
             None
         };
 
+        let firmware_slots = self.optional(
+            controller,
+            "firmware slot log",
+            device.firmware_slot_log(timeout_ms),
+        );
+
+        let endurance_groups = if identify
+            .as_ref()
+            .is_some_and(|value| value.supports_endurance_groups())
+        {
+            self.collect_endurance_groups(controller, &device, timeout_ms)
+        } else {
+            Vec::new()
+        };
+
+        let persistent_events = if self.config.collect_persistent_event_log
+            && identify
+                .as_ref()
+                .is_some_and(|value| value.supports_persistent_event_log())
+        {
+            self.optional(
+                controller,
+                "persistent event log",
+                device.persistent_event_log(timeout_ms),
+            )
+            .map(|log| PersistentEventLogSnapshot {
+                total_events: log.header.total_events,
+                total_log_length: log.header.total_log_length,
+                generation_number: log.header.generation_number,
+                events: log.events,
+                event_totals: BTreeMap::new(),
+            })
+        } else {
+            None
+        };
+
+        let telemetry = if identify
+            .as_ref()
+            .is_some_and(|value| value.supports_telemetry())
+        {
+            self.optional(
+                controller,
+                "controller-initiated telemetry log",
+                device.controller_telemetry_header(timeout_ms),
+            )
+        } else {
+            None
+        };
+
+        let power_state = self
+            .optional(
+                controller,
+                "power management feature",
+                device.power_management(FeatureSelect::Current, timeout_ms),
+            )
+            .map(|value| value.power_state);
+
+        let features = if self.config.collect_features {
+            let supports_timestamp = identify
+                .as_ref()
+                .is_some_and(|value| value.supports_timestamp());
+            let supports_apst = identify.as_ref().is_some_and(|value| value.supports_apst());
+            let write_cache_present = identify
+                .as_ref()
+                .is_some_and(|value| value.volatile_write_cache_present());
+            let host_memory_buffer_requested =
+                identify.as_ref().is_some_and(|value| value.hmpre > 0);
+            Some(FeatureSnapshot {
+                arbitration: self.optional(
+                    controller,
+                    "arbitration feature",
+                    device.arbitration(FeatureSelect::Current, timeout_ms),
+                ),
+                number_of_queues: self.optional(
+                    controller,
+                    "number of queues feature",
+                    device.number_of_queues(FeatureSelect::Current, timeout_ms),
+                ),
+                timestamp: if supports_timestamp {
+                    self.optional(
+                        controller,
+                        "timestamp feature",
+                        device.timestamp(FeatureSelect::Current, timeout_ms),
+                    )
+                } else {
+                    None
+                },
+                temperature_thresholds: self
+                    .collect_temperature_thresholds(controller, &device, &smart, timeout_ms),
+                apst: if supports_apst {
+                    self.optional(
+                        controller,
+                        "autonomous power state transition feature",
+                        device
+                            .autonomous_power_state_transition(FeatureSelect::Current, timeout_ms),
+                    )
+                } else {
+                    None
+                },
+                volatile_write_cache: if write_cache_present {
+                    self.optional(
+                        controller,
+                        "volatile write cache feature",
+                        device.volatile_write_cache(FeatureSelect::Current, timeout_ms),
+                    )
+                } else {
+                    None
+                },
+                host_memory_buffer: if host_memory_buffer_requested {
+                    self.optional(
+                        controller,
+                        "host memory buffer feature",
+                        device.host_memory_buffer(FeatureSelect::Current, timeout_ms),
+                    )
+                } else {
+                    None
+                },
+            })
+        } else {
+            None
+        };
+
+        let vendor_logs = if self.config.collect_vendor_logs {
+            let intel_vendor = identify
+                .as_ref()
+                .is_some_and(|value| IntelSmartLog::supported_vendor(value.vendor_id));
+            Some(VendorLogSnapshot {
+                ocp_smart: self.vendor_log(
+                    controller,
+                    LID_OCP_SMART,
+                    "OCP SMART / health log",
+                    || device.ocp_smart_log(timeout_ms),
+                ),
+                ocp_latency_monitor: self.vendor_log(
+                    controller,
+                    LID_OCP_LATENCY_MONITOR,
+                    "OCP latency monitor log",
+                    || device.ocp_latency_monitor_log(timeout_ms),
+                ),
+                intel_smart: if intel_vendor {
+                    self.vendor_log(
+                        controller,
+                        LID_INTEL_SMART,
+                        "Intel extended SMART log",
+                        || device.intel_smart_log(timeout_ms),
+                    )
+                } else {
+                    None
+                },
+                custom: self.collect_custom_log_pages(
+                    controller,
+                    &device,
+                    identify.as_ref().map(|value| value.vendor_id),
+                    &model,
+                    timeout_ms,
+                ),
+            })
+        } else {
+            None
+        };
+
         Ok(DeviceSnapshot {
             device: controller.name.clone(),
             model,
             serial,
             firmware,
             accessible: true,
+            controller: identify,
             smart: Some(smart),
             namespaces,
             error_log,
             self_test,
+            firmware_slots,
+            endurance_groups,
+            persistent_events,
+            telemetry,
+            power_state,
+            features,
+            vendor_logs,
+        })
+    }
+
+    fn vendor_log<T>(
+        &self,
+        controller: &NvmeController,
+        lid: u8,
+        what: &str,
+        read: impl FnOnce() -> Result<Option<T>, NvmeError>,
+    ) -> Option<T> {
+        let key = VendorLogKey {
+            device: controller.name.clone(),
+            lid,
+        };
+        let known = self
+            .state
+            .lock()
+            .ok()
+            .and_then(|state| state.vendor_log_support.get(&key).copied());
+        if known == Some(false) {
+            return None;
+        }
+
+        let supported = match read() {
+            Ok(Some(value)) => Some(value),
+            Ok(None) => {
+                info!(
+                    controller = %controller.name,
+                    lid = format!("0x{:02x}", lid),
+                    "{} not recognised, skipping it for this device",
+                    what
+                );
+                None
+            }
+            Err(error @ NvmeError::CommandStatus { .. }) if known.is_none() => {
+                self.record_command_error(controller, &error);
+                info!(
+                    controller = %controller.name,
+                    error = %error,
+                    "{} not supported, skipping it for this device",
+                    what
+                );
+                None
+            }
+            Err(error) => return self.optional(controller, what, Err(error)),
+        };
+
+        if let Ok(mut state) = self.state.lock() {
+            state.vendor_log_support.insert(key, supported.is_some());
+        }
+        supported
+    }
+
+    fn namespace_mismatch_is_new(&self, namespace: &ReconciledNamespace) -> bool {
+        let mismatched = !namespace.active || !namespace.block_device;
+        let Ok(mut state) = self.state.lock() else {
+            return mismatched;
+        };
+        if mismatched {
+            state.namespace_mismatches.insert(namespace.name.clone())
+        } else {
+            state.namespace_mismatches.remove(&namespace.name);
+            false
+        }
+    }
+
+    fn collect_endurance_groups(
+        &self,
+        controller: &NvmeController,
+        device: &NvmeDevice,
+        timeout_ms: u32,
+    ) -> Vec<EnduranceGroupSnapshot> {
+        let Some(ids) = self.optional(
+            controller,
+            "endurance group list",
+            device.endurance_group_ids(timeout_ms),
+        ) else {
+            return Vec::new();
+        };
+
+        ids.into_iter()
+            .filter_map(|id| {
+                let log = self.optional(
+                    controller,
+                    "endurance group information log",
+                    device.endurance_group_log(id, timeout_ms),
+                )?;
+                Some(EnduranceGroupSnapshot { id, log })
+            })
+            .collect()
+    }
+
+    fn collect_custom_log_pages(
+        &self,
+        controller: &NvmeController,
+        device: &NvmeDevice,
+        vendor_id: Option<u16>,
+        model: &str,
+        timeout_ms: u32,
+    ) -> Vec<CustomLogSnapshot> {
+        self.config
+            .log_page_decoders
+            .iter()
+            .filter(|page| page.matches(vendor_id, model))
+            .filter_map(|page| {
+                let result = device
+                    .log_page(page.nsid, page.lid, page.length, timeout_ms)
+                    .and_then(|bytes| page.decode(&bytes));
+                self.optional(controller, &format!("{} log page", page.name), result)
+            })
+            .collect()
+    }
+
+    fn collect_zoned_namespace(
+        &self,
+        controller: &NvmeController,
+        device: &NvmeDevice,
+        namespace: &ReconciledNamespace,
+        timeout_ms: u32,
+    ) -> Option<ZonedNamespaceSnapshot> {
+        let identify = self.optional(
+            controller,
+            "zoned namespace identify",
+            device.zoned_namespace(namespace.nsid, timeout_ms),
+        )?;
+
+        let mut zones_by_state = BTreeMap::new();
+        if namespace.block_device {
+            let path = controller.dev_path.with_file_name(&namespace.name);
+            if let Some(namespace_device) =
+                self.optional(controller, "zoned namespace open", NvmeDevice::open(&path))
+            {
+                for state in ZoneState::ALL {
+                    let result = namespace_device.zones_in_state(namespace.nsid, state, timeout_ms);
+                    match self.optional(controller, "zone report", result) {
+                        Some(count) => {
+                            zones_by_state.insert(state, count);
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        Some(ZonedNamespaceSnapshot {
+            identify,
+            zones_by_state,
         })
     }
 
+    fn collect_temperature_thresholds(
+        &self,
+        controller: &NvmeController,
+        device: &NvmeDevice,
+        smart: &SmartLog,
+        timeout_ms: u32,
+    ) -> Vec<TemperatureThresholdSnapshot> {
+        let mut thresholds = Vec::new();
+        let mut sensor = 0_u8;
+        while sensor <= 8 {
+            let implemented = sensor == 0
+                || smart
+                    .temp_sensor_kelvin
+                    .get(usize::from(sensor - 1))
+                    .is_some_and(|value| *value != 0);
+            if implemented {
+                for under in [false, true] {
+                    let result = device.temperature_threshold(
+                        sensor,
+                        under,
+                        FeatureSelect::Current,
+                        timeout_ms,
+                    );
+                    match self.optional(controller, "temperature threshold feature", result) {
+                        Some(threshold) => thresholds.push(TemperatureThresholdSnapshot {
+                            sensor,
+                            under,
+                            threshold,
+                        }),
+                        None => return thresholds,
+                    }
+                }
+            }
+            sensor += 1;
+        }
+        thresholds
+    }
+
+    fn optional<T>(
+        &self,
+        controller: &NvmeController,
+        what: &str,
+        result: Result<T, NvmeError>,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.record_command_error(controller, &error);
+                warn!(
+                    controller = %controller.name,
+                    error = %error,
+                    "{} collection failed",
+                    what
+                );
+                None
+            }
+        }
+    }
+
     fn minimal_snapshot(&self, controller: &NvmeController, accessible: bool) -> DeviceSnapshot {
         DeviceSnapshot {
             device: controller.name.clone(),
@@ -252,13 +796,132 @@ impl NvmeCollector {
                 .clone()
                 .unwrap_or_else(|| "unknown".to_string()),
             accessible,
+            controller: None,
             smart: None,
             namespaces: Vec::new(),
             error_log: None,
             self_test: None,
+            firmware_slots: None,
+            endurance_groups: Vec::new(),
+            persistent_events: None,
+            telemetry: None,
+            power_state: None,
+            features: None,
+            vendor_logs: None,
+        }
+    }
+
+    pub fn record_command_error(&self, controller: &NvmeController, error: &NvmeError) {
+        let NvmeError::CommandStatus {
+            opcode,
+            lid,
+            status,
+            ..
+        } = error
+        else {
+            return;
+        };
+
+        let key = CommandErrorKey {
+            device: controller.name.clone(),
+            opcode: *opcode,
+            lid: *lid,
+            status: status.label(),
+        };
+        if let Ok(mut state) = self.state.lock() {
+            *state.command_errors.entry(key).or_insert(0) += 1;
         }
     }
 
+    fn command_error_snapshots(&self) -> Result<Vec<CommandErrorSnapshot>, NvmeError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|error| NvmeError::Internal(format!("collector mutex poisoned: {}", error)))?;
+
+        let known_devices: HashSet<String> = state.devices.keys().cloned().collect();
+        state
+            .command_errors
+            .retain(|key, _| known_devices.contains(&key.device));
+        state
+            .vendor_log_support
+            .retain(|key, _| known_devices.contains(&key.device));
+
+        Ok(state
+            .command_errors
+            .iter()
+            .map(|(key, count)| CommandErrorSnapshot {
+                device: key.device.clone(),
+                opcode: key.opcode,
+                lid: key.lid,
+                status: key.status.clone(),
+                count: *count,
+            })
+            .collect())
+    }
+
+    pub fn record_self_test_outcome(&self, device: &str, test_type: &str, outcome: &str) {
+        let key = SelfTestOutcomeKey {
+            device: device.to_string(),
+            test_type: test_type.to_string(),
+            outcome: outcome.to_string(),
+        };
+        if let Ok(mut state) = self.state.lock() {
+            *state.self_test_outcomes.entry(key).or_insert(0) += 1;
+        }
+    }
+
+    fn self_test_schedule_snapshots(&self) -> Result<Vec<SelfTestScheduleSnapshot>, NvmeError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|error| NvmeError::Internal(format!("collector mutex poisoned: {}", error)))?;
+
+        let known_devices: HashSet<String> = state.devices.keys().cloned().collect();
+        state
+            .self_test_outcomes
+            .retain(|key, _| known_devices.contains(&key.device));
+
+        Ok(state
+            .self_test_outcomes
+            .iter()
+            .map(|(key, count)| SelfTestScheduleSnapshot {
+                device: key.device.clone(),
+                test_type: key.test_type.clone(),
+                outcome: key.outcome.clone(),
+                count: *count,
+            })
+            .collect())
+    }
+
+    fn track_persistent_events(&self, controller: &NvmeController, snapshot: &mut DeviceSnapshot) {
+        let Some(current) = snapshot.persistent_events.as_mut() else {
+            return;
+        };
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let tracker = state
+            .persistent_events
+            .entry(controller.name.clone())
+            .or_default();
+        track_persistent_events(&controller.name, tracker, current);
+    }
+
+    fn track_self_test_failures(&self, controller: &NvmeController, snapshot: &mut DeviceSnapshot) {
+        let Some(current) = snapshot.self_test.as_mut() else {
+            return;
+        };
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let tracker = state
+            .self_test_failures
+            .entry(controller.name.clone())
+            .or_default();
+        track_self_test_failures(tracker, current);
+    }
+
     fn load_previous_devices(&self) -> Result<HashMap<String, CachedDevice>, NvmeError> {
         let state = self
             .state
@@ -302,6 +965,14 @@ impl NvmeCollector {
                 now.saturating_duration_since(cached.last_seen) <= grace
             }
         });
+        let CollectorState {
+            devices,
+            persistent_events,
+            self_test_failures,
+            ..
+        } = &mut *state;
+        persistent_events.retain(|name, _| devices.contains_key(name));
+        self_test_failures.retain(|name, _| devices.contains_key(name));
 
         let mut snapshots: Vec<DeviceSnapshot> = state
             .devices
@@ -338,3 +1009,340 @@ impl NvmeCollector {
         Ok(controllers)
     }
 }
+
+fn log_new_error_entries(
+    controller: &NvmeController,
+    previous: Option<&CachedDevice>,
+    snapshot: &DeviceSnapshot,
+) {
+    let Some(error_log) = &snapshot.error_log else {
+        return;
+    };
+    let Some(previous_max) = previous
+        .and_then(|cached| cached.snapshot.error_log.as_ref())
+        .map(|value| value.max_error_count)
+    else {
+        return;
+    };
+
+    for entry in error_log
+        .entries
+        .iter()
+        .rev()
+        .filter(|entry| entry.error_count > previous_max)
+    {
+        warn!(
+            controller = %controller.name,
+            error_count = entry.error_count,
+            submission_queue_id = entry.submission_queue_id,
+            command_id = entry.command_id,
+            status = %entry.status,
+            parameter_error_byte = ?entry.parameter_error_byte,
+            parameter_error_bit = ?entry.parameter_error_bit,
+            lba = entry.lba,
+            nsid = entry.nsid,
+            vendor_specific_log_page = entry.vendor_specific_log_page,
+            transport_type = entry.transport_type,
+            command_specific = entry.command_specific,
+            "new nvme error log entry"
+        );
+    }
+}
+
+fn track_persistent_events(
+    controller: &str,
+    tracker: &mut PersistentEventTracker,
+    current: &mut PersistentEventLogSnapshot,
+) {
+    let start = match &tracker.marker {
+        Some(PersistentEventMarker {
+            generation_number,
+            newest: Some(newest),
+        }) if *generation_number == current.generation_number => current
+            .events
+            .iter()
+            .rposition(|event| event == newest)
+            .map(|index| index + 1)
+            .unwrap_or_else(|| {
+                current
+                    .events
+                    .iter()
+                    .position(|event| event.timestamp_ms > newest.timestamp_ms)
+                    .unwrap_or(current.events.len())
+            }),
+        _ => 0,
+    };
+
+    for event in &current.events[start..] {
+        *tracker.event_totals.entry(event.type_name()).or_default() += 1;
+        if tracker.marker.is_some() {
+            info!(
+                controller = %controller,
+                event_type = %event.type_name(),
+                revision = event.revision,
+                controller_id = event.controller_id,
+                timestamp_ms = event.timestamp_ms,
+                generation = current.generation_number,
+                detail = %event.detail(),
+                "new nvme persistent event"
+            );
+        }
+    }
+
+    tracker.marker = Some(PersistentEventMarker {
+        generation_number: current.generation_number,
+        newest: current.events.pop(),
+    });
+    current.events.clear();
+    current.event_totals = tracker.event_totals.clone();
+}
+
+fn track_self_test_failures(tracker: &mut SelfTestFailureTracker, current: &mut SelfTestSnapshot) {
+    let mut seen = BTreeSet::new();
+    for result in current.results.iter().filter(|result| result.failed()) {
+        let key = SelfTestFailureKey {
+            operation: result.operation,
+            result: result.result,
+            segment_number: result.segment_number,
+            power_on_hours: result.power_on_hours,
+        };
+        if !tracker.seen.contains(&key) {
+            *tracker
+                .failure_totals
+                .entry(result.operation_name())
+                .or_default() += 1;
+        }
+        seen.insert(key);
+    }
+
+    tracker.seen = seen;
+    current.failure_totals = tracker.failure_totals.clone();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+    use std::net::SocketAddr;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use crate::collector::NvmeCollector;
+    use crate::config::Config;
+    use crate::config::LogFormat;
+    use crate::metrics::PersistentEventLogSnapshot;
+    use crate::metrics::SelfTestSnapshot;
+    use crate::nvme::discovery::NvmeController;
+    use crate::nvme::types::PersistentEvent;
+    use crate::nvme::types::SelfTestResult;
+    use crate::nvme::types::PERSISTENT_EVENT_FIRMWARE_COMMIT;
+    use crate::nvme::types::PERSISTENT_EVENT_THERMAL_EXCURSION;
+
+    fn collector() -> NvmeCollector {
+        NvmeCollector::new(Config {
+            command: None,
+            listen_address: SocketAddr::from(([127, 0, 0, 1], 9998)),
+            devices: "/dev/nvme*".to_string(),
+            discovery_interval: Duration::from_secs(30),
+            stale_device_grace: Duration::from_secs(300),
+            collect_namespace: true,
+            collect_error_log: true,
+            collect_self_test: true,
+            collect_features: true,
+            collect_vendor_logs: true,
+            collect_persistent_event_log: true,
+            log_page_decoders: Vec::new(),
+            telemetry_dir: PathBuf::from("/tmp"),
+            telemetry_max_bytes: 1024,
+            admin_endpoints: false,
+            admin_listen_address: SocketAddr::from(([127, 0, 0, 1], 9999)),
+            log_level: "info".to_string(),
+            log_format: LogFormat::Text,
+            ioctl_timeout: Duration::from_millis(5000),
+            self_test_short_schedule: None,
+            self_test_extended_schedule: None,
+        })
+    }
+
+    fn controller() -> NvmeController {
+        NvmeController {
+            name: "nvme0".to_string(),
+            dev_path: PathBuf::from("/dev/nvme0"),
+            model: None,
+            serial: None,
+            firmware: None,
+            namespaces: Vec::new(),
+        }
+    }
+
+    fn event(event_type: u8, timestamp_ms: u64) -> PersistentEvent {
+        PersistentEvent {
+            event_type,
+            revision: 0,
+            controller_id: 0,
+            timestamp_ms,
+            data: Vec::new(),
+        }
+    }
+
+    fn persistent_event_totals(
+        collector: &NvmeCollector,
+        generation_number: u16,
+        events: Option<Vec<PersistentEvent>>,
+    ) -> Option<BTreeMap<String, u64>> {
+        let controller = controller();
+        let mut snapshot = collector.minimal_snapshot(&controller, true);
+        snapshot.persistent_events = events.map(|events| PersistentEventLogSnapshot {
+            total_events: events.len() as u32,
+            total_log_length: 0,
+            generation_number,
+            events,
+            event_totals: BTreeMap::new(),
+        });
+        collector.track_persistent_events(&controller, &mut snapshot);
+        snapshot.persistent_events.map(|value| value.event_totals)
+    }
+
+    fn self_test_result(operation: u8, result: u8, power_on_hours: u64) -> SelfTestResult {
+        SelfTestResult {
+            operation,
+            result,
+            segment_number: 0,
+            power_on_hours,
+            nsid: None,
+            failing_lba: None,
+            status_code_type: None,
+            status_code: None,
+            vendor_specific: 0,
+        }
+    }
+
+    fn self_test_failure_totals(
+        collector: &NvmeCollector,
+        results: Option<Vec<SelfTestResult>>,
+    ) -> Option<BTreeMap<String, u64>> {
+        let controller = controller();
+        let mut snapshot = collector.minimal_snapshot(&controller, true);
+        snapshot.self_test = results.map(|results| SelfTestSnapshot {
+            current_operation: 0,
+            current_completion_ratio: 0.0,
+            results,
+            failure_totals: BTreeMap::new(),
+        });
+        collector.track_self_test_failures(&controller, &mut snapshot);
+        snapshot.self_test.map(|value| value.failure_totals)
+    }
+
+    #[test]
+    fn self_test_failures_are_counted_once_across_scrapes() {
+        let collector = collector();
+        let totals = self_test_failure_totals(
+            &collector,
+            Some(vec![
+                self_test_result(1, 0x7, 10),
+                self_test_result(1, 0x0, 5),
+            ]),
+        )
+        .expect("first read should report totals");
+        assert_eq!(totals.get("short"), Some(&1));
+
+        assert!(self_test_failure_totals(&collector, None).is_none());
+
+        let totals = self_test_failure_totals(
+            &collector,
+            Some(vec![
+                self_test_result(2, 0x5, 20),
+                self_test_result(1, 0x0, 15),
+                self_test_result(1, 0x7, 10),
+            ]),
+        )
+        .expect("second read should report totals");
+        assert_eq!(totals.get("short"), Some(&1));
+        assert_eq!(totals.get("extended"), Some(&1));
+
+        let totals = self_test_failure_totals(&collector, Some(vec![self_test_result(1, 0x0, 30)]))
+            .expect("third read should report totals");
+        assert_eq!(totals.get("short"), Some(&1));
+        assert_eq!(totals.get("extended"), Some(&1));
+    }
+
+    #[test]
+    fn self_test_outcomes_are_pruned_with_their_device() {
+        let collector = collector();
+        let controller = controller();
+        collector.record_self_test_outcome("nvme0", "short", "started");
+        collector.record_self_test_outcome("nvme1", "short", "started");
+        collector
+            .merge_device_state(
+                Instant::now(),
+                &HashSet::from([controller.name.clone()]),
+                HashMap::from([(
+                    controller.name.clone(),
+                    collector.minimal_snapshot(&controller, true),
+                )]),
+            )
+            .expect("device state should merge");
+
+        let outcomes = collector
+            .self_test_schedule_snapshots()
+            .expect("outcomes should be reported");
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].device, "nvme0");
+    }
+
+    #[test]
+    fn persistent_event_totals_carry_over_a_failed_read() {
+        let collector = collector();
+        let first = vec![
+            event(PERSISTENT_EVENT_FIRMWARE_COMMIT, 1_000),
+            event(PERSISTENT_EVENT_THERMAL_EXCURSION, 2_000),
+        ];
+        let totals = persistent_event_totals(&collector, 1, Some(first.clone()))
+            .expect("first read should report totals");
+        assert_eq!(totals.get("firmware_commit"), Some(&1));
+        assert_eq!(totals.get("thermal_excursion"), Some(&1));
+
+        assert!(persistent_event_totals(&collector, 1, None).is_none());
+
+        let totals = persistent_event_totals(&collector, 1, Some(first))
+            .expect("second read should report totals");
+        assert_eq!(totals.get("firmware_commit"), Some(&1));
+        assert_eq!(totals.get("thermal_excursion"), Some(&1));
+    }
+
+    #[test]
+    fn persistent_events_after_a_wrapped_marker_are_counted_once() {
+        let collector = collector();
+        persistent_event_totals(
+            &collector,
+            1,
+            Some(vec![
+                event(PERSISTENT_EVENT_FIRMWARE_COMMIT, 1_000),
+                event(PERSISTENT_EVENT_THERMAL_EXCURSION, 2_000),
+            ]),
+        );
+
+        let totals = persistent_event_totals(
+            &collector,
+            1,
+            Some(vec![
+                event(PERSISTENT_EVENT_THERMAL_EXCURSION, 1_500),
+                event(PERSISTENT_EVENT_THERMAL_EXCURSION, 3_000),
+            ]),
+        )
+        .expect("read should report totals");
+        assert_eq!(totals.get("firmware_commit"), Some(&1));
+        assert_eq!(totals.get("thermal_excursion"), Some(&2));
+
+        let totals = persistent_event_totals(
+            &collector,
+            2,
+            Some(vec![event(PERSISTENT_EVENT_FIRMWARE_COMMIT, 500)]),
+        )
+        .expect("new generation should report totals");
+        assert_eq!(totals.get("firmware_commit"), Some(&2));
+        assert_eq!(totals.get("thermal_excursion"), Some(&2));
+    }
+}
@@ -1,11 +1,17 @@
 use std::net::SocketAddr;
+use std::path::Path;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::time::Duration;
 
 use clap::Parser;
+use clap::Subcommand;
 use clap::ValueEnum;
 
 use crate::nvme::error::NvmeError;
+use crate::nvme::vendor::custom::load_custom_log_pages;
+use crate::nvme::vendor::custom::CustomLogPage;
+use crate::scheduler::CronSchedule;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
 pub enum LogFormat {
@@ -13,8 +19,14 @@ pub enum LogFormat {
     Json,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Subcommand)]
+pub enum Command {
+    CaptureTelemetry { device: String },
+}
+
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub command: Option<Command>,
     pub listen_address: SocketAddr,
     pub devices: String,
     pub discovery_interval: Duration,
@@ -22,9 +34,19 @@ pub struct Config {
     pub collect_namespace: bool,
     pub collect_error_log: bool,
     pub collect_self_test: bool,
+    pub collect_features: bool,
+    pub collect_vendor_logs: bool,
+    pub collect_persistent_event_log: bool,
+    pub log_page_decoders: Vec<CustomLogPage>,
+    pub telemetry_dir: PathBuf,
+    pub telemetry_max_bytes: u64,
+    pub admin_endpoints: bool,
+    pub admin_listen_address: SocketAddr,
     pub log_level: String,
     pub log_format: LogFormat,
     pub ioctl_timeout: Duration,
+    pub self_test_short_schedule: Option<CronSchedule>,
+    pub self_test_extended_schedule: Option<CronSchedule>,
 }
 
 impl Config {
@@ -37,6 +59,13 @@ impl Config {
                 args.listen_address, error
             ))
         })?;
+        let admin_listen_address =
+            SocketAddr::from_str(&args.admin_listen_address).map_err(|error| {
+                NvmeError::Parse(format!(
+                    "invalid admin listen address '{}': {}",
+                    args.admin_listen_address, error
+                ))
+            })?;
         if args.discovery_interval == 0 {
             return Err(NvmeError::Parse(
                 "discovery interval must be greater than zero".to_string(),
@@ -47,8 +76,22 @@ impl Config {
                 "stale-device-grace must be greater than zero".to_string(),
             ));
         }
+        if args.telemetry_max_bytes == 0 {
+            return Err(NvmeError::Parse(
+                "telemetry-max-bytes must be greater than zero".to_string(),
+            ));
+        }
+
+        let self_test_short_schedule = parse_schedule(args.self_test_short_schedule.as_deref())?;
+        let self_test_extended_schedule =
+            parse_schedule(args.self_test_extended_schedule.as_deref())?;
+        let log_page_decoders = match args.log_page_decoders.as_deref() {
+            Some(path) if !path.trim().is_empty() => load_custom_log_pages(Path::new(path))?,
+            _ => Vec::new(),
+        };
 
         Ok(Self {
+            command: args.command,
             listen_address,
             devices: args.devices,
             discovery_interval: Duration::from_secs(args.discovery_interval),
@@ -56,17 +99,41 @@ impl Config {
             collect_namespace: args.collect_namespace,
             collect_error_log: args.collect_error_log,
             collect_self_test: args.collect_self_test,
+            collect_features: args.collect_features,
+            collect_vendor_logs: args.collect_vendor_logs,
+            collect_persistent_event_log: args.collect_persistent_event_log,
+            log_page_decoders,
+            telemetry_dir: PathBuf::from(args.telemetry_dir),
+            telemetry_max_bytes: args.telemetry_max_bytes,
+            admin_endpoints: args.admin_endpoints,
+            admin_listen_address,
             log_level: args.log_level,
             log_format: args.log_format,
             ioctl_timeout: Duration::from_millis(5000),
+            self_test_short_schedule,
+            self_test_extended_schedule,
         })
     }
+
+    pub fn self_test_scheduler_enabled(&self) -> bool {
+        self.self_test_short_schedule.is_some() || self.self_test_extended_schedule.is_some()
+    }
+}
+
+fn parse_schedule(value: Option<&str>) -> Result<Option<CronSchedule>, NvmeError> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(expression) => CronSchedule::parse(expression).map(Some),
+    }
 }
 
 #[derive(Clone, Debug, Parser)]
 #[command(name = "nvme-exporter")]
 #[command(about = "Prometheus exporter for NVMe health metrics")]
 struct CliArgs {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     #[arg(
         short = 'l',
         long = "listen-address",
@@ -123,6 +190,76 @@ struct CliArgs {
     )]
     collect_self_test: bool,
 
+    #[arg(
+        long = "collect-features",
+        env = "NVME_EXPORTER_COLLECT_FEATURES",
+        default_value_t = true,
+        action = clap::ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    collect_features: bool,
+
+    #[arg(
+        long = "collect-vendor-logs",
+        env = "NVME_EXPORTER_COLLECT_VENDOR_LOGS",
+        default_value_t = true,
+        action = clap::ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    collect_vendor_logs: bool,
+
+    #[arg(
+        long = "collect-persistent-event-log",
+        env = "NVME_EXPORTER_COLLECT_PERSISTENT_EVENT_LOG",
+        default_value_t = false,
+        action = clap::ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    collect_persistent_event_log: bool,
+
+    #[arg(long = "log-page-decoders", env = "NVME_EXPORTER_LOG_PAGE_DECODERS")]
+    log_page_decoders: Option<String>,
+
+    #[arg(
+        long = "telemetry-dir",
+        env = "NVME_EXPORTER_TELEMETRY_DIR",
+        default_value = "/var/lib/nvme-exporter/telemetry",
+        global = true
+    )]
+    telemetry_dir: String,
+
+    #[arg(
+        long = "telemetry-max-bytes",
+        env = "NVME_EXPORTER_TELEMETRY_MAX_BYTES",
+        default_value_t = 1024 * 1024 * 1024_u64,
+        global = true
+    )]
+    telemetry_max_bytes: u64,
+
+    #[arg(
+        long = "admin-endpoints",
+        env = "NVME_EXPORTER_ADMIN_ENDPOINTS",
+        default_value_t = false,
+        action = clap::ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    admin_endpoints: bool,
+
+    #[arg(
+        long = "admin-listen-address",
+        env = "NVME_EXPORTER_ADMIN_LISTEN_ADDRESS",
+        default_value = "127.0.0.1:9999"
+    )]
+    admin_listen_address: String,
+
     #[arg(
         long = "stale-device-grace",
         env = "NVME_EXPORTER_STALE_DEVICE_GRACE",
@@ -130,6 +267,18 @@ struct CliArgs {
     )]
     stale_device_grace: u64,
 
+    #[arg(
+        long = "self-test-short-schedule",
+        env = "NVME_EXPORTER_SELF_TEST_SHORT_SCHEDULE"
+    )]
+    self_test_short_schedule: Option<String>,
+
+    #[arg(
+        long = "self-test-extended-schedule",
+        env = "NVME_EXPORTER_SELF_TEST_EXTENDED_SCHEDULE"
+    )]
+    self_test_extended_schedule: Option<String>,
+
     #[arg(
         long = "log-level",
         env = "NVME_EXPORTER_LOG_LEVEL",
@@ -150,7 +299,9 @@ struct CliArgs {
 mod tests {
     use clap::Parser;
 
+    use crate::config::parse_schedule;
     use crate::config::CliArgs;
+    use crate::config::Command;
 
     #[test]
     fn defaults_enable_optional_collectors() {
@@ -158,6 +309,16 @@ mod tests {
         assert!(args.collect_namespace);
         assert!(args.collect_error_log);
         assert!(args.collect_self_test);
+        assert!(args.collect_features);
+        assert!(args.collect_vendor_logs);
+        assert!(!args.collect_persistent_event_log);
+        assert!(args.log_page_decoders.is_none());
+        assert!(!args.admin_endpoints);
+        assert_eq!(args.admin_listen_address, "127.0.0.1:9999");
+        assert_eq!(args.telemetry_max_bytes, 1024 * 1024 * 1024);
+        assert!(args.command.is_none());
+        assert!(args.self_test_short_schedule.is_none());
+        assert!(args.self_test_extended_schedule.is_none());
     }
 
     #[test]
@@ -167,9 +328,58 @@ mod tests {
             "--collect-namespace=false",
             "--collect-error-log=false",
             "--collect-self-test=false",
+            "--collect-features=false",
+            "--collect-vendor-logs=false",
+            "--collect-persistent-event-log=false",
         ]);
         assert!(!args.collect_namespace);
         assert!(!args.collect_error_log);
         assert!(!args.collect_self_test);
+        assert!(!args.collect_features);
+        assert!(!args.collect_vendor_logs);
+        assert!(!args.collect_persistent_event_log);
+    }
+
+    #[test]
+    fn persistent_event_log_is_opt_in() {
+        let args = CliArgs::parse_from(["nvme-exporter", "--collect-persistent-event-log"]);
+        assert!(args.collect_persistent_event_log);
+    }
+
+    #[test]
+    fn capture_telemetry_subcommand_parses() {
+        let args = CliArgs::parse_from([
+            "nvme-exporter",
+            "capture-telemetry",
+            "nvme1",
+            "--telemetry-dir",
+            "/tmp/telemetry",
+        ]);
+        assert_eq!(
+            args.command,
+            Some(Command::CaptureTelemetry {
+                device: "nvme1".to_string()
+            })
+        );
+        assert_eq!(args.telemetry_dir, "/tmp/telemetry");
+    }
+
+    #[test]
+    fn self_test_schedules_parse() {
+        let args = CliArgs::parse_from([
+            "nvme-exporter",
+            "--self-test-short-schedule",
+            "0 2 * * *",
+            "--self-test-extended-schedule",
+            "0 3 * * 0",
+        ]);
+        assert_eq!(args.self_test_short_schedule.as_deref(), Some("0 2 * * *"));
+        assert!(parse_schedule(args.self_test_extended_schedule.as_deref())
+            .expect("schedule should parse")
+            .is_some());
+        assert!(parse_schedule(Some("  "))
+            .expect("blank is disabled")
+            .is_none());
+        assert!(parse_schedule(Some("not a schedule")).is_err());
     }
 }
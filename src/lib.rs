@@ -2,4 +2,6 @@ pub mod collector;
 pub mod config;
 pub mod metrics;
 pub mod nvme;
+pub mod scheduler;
 pub mod server;
+pub mod telemetry;
@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
 use nvme_exporter::collector::NvmeCollector;
+use nvme_exporter::config::Command;
 use nvme_exporter::config::Config;
 use nvme_exporter::config::LogFormat;
 use nvme_exporter::nvme::error::NvmeError;
+use nvme_exporter::scheduler;
 use nvme_exporter::server;
+use nvme_exporter::telemetry;
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
@@ -20,6 +23,10 @@ async fn run() -> Result<(), NvmeError> {
     let config = Config::parse()?;
     init_logging(&config)?;
 
+    if let Some(Command::CaptureTelemetry { device }) = &config.command {
+        return capture_telemetry(&config, device);
+    }
+
     let collector = Arc::new(NvmeCollector::new(config.clone()));
     collector.validate_startup_devices()?;
 
@@ -29,9 +36,55 @@ async fn run() -> Result<(), NvmeError> {
         "starting nvme-exporter"
     );
 
+    if config.self_test_scheduler_enabled() {
+        let timeout_ms = u32::try_from(config.ioctl_timeout.as_millis())
+            .map_err(|_| NvmeError::Parse("ioctl timeout exceeds u32".to_string()))?;
+        let short = config.self_test_short_schedule.clone();
+        let extended = config.self_test_extended_schedule.clone();
+        info!(
+            short = short
+                .as_ref()
+                .map(|value| value.expression())
+                .unwrap_or("disabled"),
+            extended = extended
+                .as_ref()
+                .map(|value| value.expression())
+                .unwrap_or("disabled"),
+            "self-test scheduler enabled (UTC)"
+        );
+        tokio::spawn(scheduler::run_self_test_scheduler(
+            collector.clone(),
+            config.devices.clone(),
+            short,
+            extended,
+            timeout_ms,
+        ));
+    }
+
     server::run_server(&config, collector).await
 }
 
+fn capture_telemetry(config: &Config, device: &str) -> Result<(), NvmeError> {
+    let timeout_ms = u32::try_from(config.ioctl_timeout.as_millis())
+        .map_err(|_| NvmeError::Parse("ioctl timeout exceeds u32".to_string()))?;
+    let controller = telemetry::find_controller(&config.devices, device)?.ok_or_else(|| {
+        NvmeError::InvalidData(format!(
+            "no NVMe controller named {} matches {}",
+            device, config.devices
+        ))
+    })?;
+
+    let capture = telemetry::capture_host_telemetry(
+        &controller,
+        &config.telemetry_dir,
+        config.telemetry_max_bytes,
+        timeout_ms,
+    )?;
+    println!("{}", capture.blob_path.display());
+    println!("{}", capture.metadata_path.display());
+    Ok(())
+}
+
 fn init_logging(config: &Config) -> Result<(), NvmeError> {
     let env_filter = EnvFilter::try_new(config.log_level.clone()).map_err(|error| {
         NvmeError::Parse(format!(
@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
 use prometheus::CounterVec;
 use prometheus::Encoder;
 use prometheus::Gauge;
@@ -5,28 +8,124 @@ use prometheus::GaugeVec;
 use prometheus::Opts;
 use prometheus::Registry;
 use prometheus::TextEncoder;
+use tracing::warn;
 
 use crate::nvme::error::NvmeError;
+use crate::nvme::types::Arbitration;
+use crate::nvme::types::AutonomousPowerStateTransition;
+use crate::nvme::types::EnduranceGroupLog;
+use crate::nvme::types::ErrorLogEntry;
+use crate::nvme::types::FirmwareSlotLog;
+use crate::nvme::types::HostMemoryBuffer;
+use crate::nvme::types::IdentifyController;
+use crate::nvme::types::IdentifyNamespace;
+use crate::nvme::types::NamespaceIdentificationDescriptors;
+use crate::nvme::types::NumberOfQueues;
+use crate::nvme::types::PersistentEvent;
+use crate::nvme::types::SelfTestResult;
 use crate::nvme::types::SmartLog;
+use crate::nvme::types::TelemetryLogHeader;
+use crate::nvme::types::TemperatureThreshold;
+use crate::nvme::types::Timestamp;
+use crate::nvme::types::VolatileWriteCache;
+use crate::nvme::types::ZoneState;
+use crate::nvme::types::ZonedNamespace;
+use crate::nvme::vendor::custom::CustomLogSnapshot;
+use crate::nvme::vendor::custom::MetricKind;
+use crate::nvme::vendor::intel::IntelSmartLog;
+use crate::nvme::vendor::ocp::LatencyOperation;
+use crate::nvme::vendor::ocp::OcpLatencyMonitorLog;
+use crate::nvme::vendor::ocp::OcpSmartLog;
 
 #[derive(Clone, Debug)]
 pub struct NamespaceSnapshot {
     pub namespace: String,
-    pub nsze: u64,
-    pub ncap: u64,
-    pub nuse: u64,
+    pub nsid: u32,
+    pub active: bool,
+    pub block_device: bool,
+    pub identify: IdentifyNamespace,
+    pub smart: Option<SmartLog>,
+    pub descriptors: Option<NamespaceIdentificationDescriptors>,
+    pub zoned: Option<ZonedNamespaceSnapshot>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct VendorLogSnapshot {
+    pub ocp_smart: Option<OcpSmartLog>,
+    pub ocp_latency_monitor: Option<OcpLatencyMonitorLog>,
+    pub intel_smart: Option<IntelSmartLog>,
+    pub custom: Vec<CustomLogSnapshot>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ZonedNamespaceSnapshot {
+    pub identify: ZonedNamespace,
+    pub zones_by_state: BTreeMap<ZoneState, u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EnduranceGroupSnapshot {
+    pub id: u16,
+    pub log: EnduranceGroupLog,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PersistentEventLogSnapshot {
+    pub total_events: u32,
+    pub total_log_length: u64,
+    pub generation_number: u16,
+    pub events: Vec<PersistentEvent>,
+    pub event_totals: BTreeMap<String, u64>,
 }
 
 #[derive(Clone, Debug)]
 pub struct ErrorLogSnapshot {
     pub non_zero_entries: u64,
     pub max_error_count: u64,
+    pub entries: Vec<ErrorLogEntry>,
 }
 
 #[derive(Clone, Debug)]
 pub struct SelfTestSnapshot {
     pub current_operation: u8,
     pub current_completion_ratio: f64,
+    pub results: Vec<SelfTestResult>,
+    pub failure_totals: BTreeMap<String, u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TemperatureThresholdSnapshot {
+    pub sensor: u8,
+    pub under: bool,
+    pub threshold: TemperatureThreshold,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FeatureSnapshot {
+    pub arbitration: Option<Arbitration>,
+    pub number_of_queues: Option<NumberOfQueues>,
+    pub timestamp: Option<Timestamp>,
+    pub temperature_thresholds: Vec<TemperatureThresholdSnapshot>,
+    pub apst: Option<AutonomousPowerStateTransition>,
+    pub volatile_write_cache: Option<VolatileWriteCache>,
+    pub host_memory_buffer: Option<HostMemoryBuffer>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CommandErrorSnapshot {
+    pub device: String,
+    pub opcode: u8,
+    pub lid: Option<u8>,
+    pub status: String,
+    pub count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct SelfTestScheduleSnapshot {
+    pub device: String,
+    pub test_type: String,
+    pub outcome: String,
+    pub count: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -36,10 +135,18 @@ pub struct DeviceSnapshot {
     pub serial: String,
     pub firmware: String,
     pub accessible: bool,
+    pub controller: Option<IdentifyController>,
     pub smart: Option<SmartLog>,
     pub namespaces: Vec<NamespaceSnapshot>,
     pub error_log: Option<ErrorLogSnapshot>,
     pub self_test: Option<SelfTestSnapshot>,
+    pub firmware_slots: Option<FirmwareSlotLog>,
+    pub endurance_groups: Vec<EnduranceGroupSnapshot>,
+    pub persistent_events: Option<PersistentEventLogSnapshot>,
+    pub telemetry: Option<TelemetryLogHeader>,
+    pub power_state: Option<u8>,
+    pub features: Option<FeatureSnapshot>,
+    pub vendor_logs: Option<VendorLogSnapshot>,
 }
 
 #[derive(Clone, Debug)]
@@ -48,9 +155,15 @@ pub struct ScrapeReport {
     pub success: bool,
     pub discovered_device_count: usize,
     pub devices: Vec<DeviceSnapshot>,
+    pub command_errors: Vec<CommandErrorSnapshot>,
+    pub kernel_default_ps_max_latency_us: Option<u64>,
+    pub self_test_schedule: Vec<SelfTestScheduleSnapshot>,
     pub collect_namespace: bool,
     pub collect_error_log: bool,
     pub collect_self_test: bool,
+    pub collect_features: bool,
+    pub collect_vendor_logs: bool,
+    pub collect_persistent_event_log: bool,
 }
 
 pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
@@ -63,6 +176,158 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
         &["device", "model", "serial", "firmware"],
     )?;
 
+    let controller_info = register_gauge_vec(
+        &registry,
+        "nvme_controller_info",
+        "NVMe controller identification from Identify Controller",
+        &[
+            "device",
+            "vendor_id",
+            "subsystem_vendor_id",
+            "ieee_oui",
+            "controller_id",
+            "version",
+            "subsystem_nqn",
+            "oacs",
+            "lpa",
+            "oncs",
+        ],
+    )?;
+    let controller_namespaces = register_gauge_vec(
+        &registry,
+        "nvme_controller_max_namespaces",
+        "Maximum number of namespaces supported by the controller",
+        &["device"],
+    )?;
+    let total_capacity_bytes = register_gauge_vec(
+        &registry,
+        "nvme_total_capacity_bytes",
+        "Total NVM capacity in bytes",
+        &["device"],
+    )?;
+    let unallocated_capacity_bytes = register_gauge_vec(
+        &registry,
+        "nvme_unallocated_capacity_bytes",
+        "Unallocated NVM capacity in bytes",
+        &["device"],
+    )?;
+
+    let power_state_max_watts = register_gauge_vec(
+        &registry,
+        "nvme_power_state_max_watts",
+        "Maximum power of each power state descriptor in watts",
+        &["device", "state"],
+    )?;
+    let power_state_entry_latency_seconds = register_gauge_vec(
+        &registry,
+        "nvme_power_state_entry_latency_seconds",
+        "Entry latency of each power state descriptor in seconds",
+        &["device", "state"],
+    )?;
+    let power_state_exit_latency_seconds = register_gauge_vec(
+        &registry,
+        "nvme_power_state_exit_latency_seconds",
+        "Exit latency of each power state descriptor in seconds",
+        &["device", "state"],
+    )?;
+    let power_state_non_operational = register_gauge_vec(
+        &registry,
+        "nvme_power_state_non_operational",
+        "1 if the power state is non-operational",
+        &["device", "state"],
+    )?;
+    let power_state_current = register_gauge_vec(
+        &registry,
+        "nvme_power_state_current",
+        "Current power state from Get Features 0x02",
+        &["device"],
+    )?;
+
+    let feature_arbitration_burst = register_gauge_vec(
+        &registry,
+        "nvme_feature_arbitration_burst",
+        "Arbitration burst from Get Features 0x01",
+        &["device"],
+    )?;
+    let feature_arbitration_weight = register_gauge_vec(
+        &registry,
+        "nvme_feature_arbitration_weight",
+        "Weighted round robin priority weights from Get Features 0x01",
+        &["device", "priority"],
+    )?;
+    let feature_queues = register_gauge_vec(
+        &registry,
+        "nvme_feature_queues",
+        "Number of I/O queues allocated from Get Features 0x07",
+        &["device", "type"],
+    )?;
+    let apst_supported = register_gauge_vec(
+        &registry,
+        "nvme_apst_supported",
+        "1 if the controller supports autonomous power state transitions",
+        &["device"],
+    )?;
+    let apst_enabled = register_gauge_vec(
+        &registry,
+        "nvme_apst_enabled",
+        "1 if autonomous power state transitions are enabled (Get Features 0x0C)",
+        &["device"],
+    )?;
+    let apst_transition_target_state = register_gauge_vec(
+        &registry,
+        "nvme_apst_transition_target_state",
+        "Power state the controller transitions to when idle in the given state",
+        &["device", "state"],
+    )?;
+    let apst_transition_idle_seconds = register_gauge_vec(
+        &registry,
+        "nvme_apst_transition_idle_seconds",
+        "Idle time before an autonomous transition out of the given state",
+        &["device", "state"],
+    )?;
+    let write_cache_present = register_gauge_vec(
+        &registry,
+        "nvme_write_cache_present",
+        "1 if the controller reports a volatile write cache",
+        &["device"],
+    )?;
+    let write_cache_enabled = register_gauge_vec(
+        &registry,
+        "nvme_write_cache_enabled",
+        "1 if the volatile write cache is enabled (Get Features 0x06)",
+        &["device"],
+    )?;
+    let host_memory_buffer_preferred_bytes = register_gauge_vec(
+        &registry,
+        "nvme_host_memory_buffer_preferred_bytes",
+        "Preferred host memory buffer size in bytes (HMPRE)",
+        &["device"],
+    )?;
+    let host_memory_buffer_minimum_bytes = register_gauge_vec(
+        &registry,
+        "nvme_host_memory_buffer_minimum_bytes",
+        "Minimum host memory buffer size in bytes (HMMIN)",
+        &["device"],
+    )?;
+    let host_memory_buffer_enabled = register_gauge_vec(
+        &registry,
+        "nvme_host_memory_buffer_enabled",
+        "1 if the host memory buffer is enabled (Get Features 0x0D)",
+        &["device"],
+    )?;
+    let host_memory_buffer_bytes = register_gauge_vec(
+        &registry,
+        "nvme_host_memory_buffer_bytes",
+        "Host memory buffer size allocated to the controller in bytes",
+        &["device"],
+    )?;
+    let controller_timestamp_seconds = register_gauge_vec(
+        &registry,
+        "nvme_controller_timestamp_seconds",
+        "Controller timestamp from Get Features 0x0E",
+        &["device"],
+    )?;
+
     let critical_warning = register_gauge_vec(
         &registry,
         "nvme_critical_warning",
@@ -106,6 +371,12 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
         "NVMe composite temperature in Celsius",
         &["device"],
     )?;
+    let temperature_threshold_celsius = register_gauge_vec(
+        &registry,
+        "nvme_temperature_threshold_celsius",
+        "Drive reported temperature thresholds in Celsius",
+        &["device", "sensor", "kind"],
+    )?;
     let temperature_sensor_celsius = register_gauge_vec(
         &registry,
         "nvme_temperature_sensor_celsius",
@@ -252,6 +523,364 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
         "Namespace utilization in LBAs",
         &["device", "namespace"],
     )?;
+    let namespace_size_bytes = register_gauge_vec(
+        &registry,
+        "nvme_namespace_size_bytes",
+        "Namespace size in bytes",
+        &["device", "namespace"],
+    )?;
+    let namespace_capacity_bytes = register_gauge_vec(
+        &registry,
+        "nvme_namespace_capacity_bytes",
+        "Namespace capacity in bytes",
+        &["device", "namespace"],
+    )?;
+    let namespace_utilization_bytes = register_gauge_vec(
+        &registry,
+        "nvme_namespace_utilization_bytes",
+        "Namespace utilization in bytes",
+        &["device", "namespace"],
+    )?;
+    let namespace_nvm_capacity_bytes = register_gauge_vec(
+        &registry,
+        "nvme_namespace_nvm_capacity_bytes",
+        "Namespace NVM capacity in bytes as reported by NVMCAP",
+        &["device", "namespace"],
+    )?;
+    let namespace_lba_size_bytes = register_gauge_vec(
+        &registry,
+        "nvme_namespace_lba_size_bytes",
+        "Data size of the formatted LBA format in bytes",
+        &["device", "namespace"],
+    )?;
+    let namespace_active = register_gauge_vec(
+        &registry,
+        "nvme_namespace_active",
+        "1 if the controller reports the namespace in its active namespace list",
+        &["device", "namespace"],
+    )?;
+    let namespace_block_device = register_gauge_vec(
+        &registry,
+        "nvme_namespace_block_device_present",
+        "1 if the namespace has a block device in sysfs",
+        &["device", "namespace"],
+    )?;
+    let namespace_data_units_read_total = register_counter_vec(
+        &registry,
+        "nvme_namespace_data_units_read_total",
+        "Data units read from the namespace",
+        &["device", "namespace"],
+    )?;
+    let namespace_data_units_written_total = register_counter_vec(
+        &registry,
+        "nvme_namespace_data_units_written_total",
+        "Data units written to the namespace",
+        &["device", "namespace"],
+    )?;
+    let namespace_host_read_commands_total = register_counter_vec(
+        &registry,
+        "nvme_namespace_host_read_commands_total",
+        "Host read commands completed for the namespace",
+        &["device", "namespace"],
+    )?;
+    let namespace_host_write_commands_total = register_counter_vec(
+        &registry,
+        "nvme_namespace_host_write_commands_total",
+        "Host write commands completed for the namespace",
+        &["device", "namespace"],
+    )?;
+    let zns_zone_size_bytes = register_gauge_vec(
+        &registry,
+        "nvme_zns_zone_size_bytes",
+        "Zone size of a zoned namespace in bytes",
+        &["device", "namespace"],
+    )?;
+    let zns_zones = register_gauge_vec(
+        &registry,
+        "nvme_zns_zones",
+        "Total number of zones in a zoned namespace",
+        &["device", "namespace"],
+    )?;
+    let zns_max_open_zones = register_gauge_vec(
+        &registry,
+        "nvme_zns_max_open_zones",
+        "Maximum number of open zones (absent when unlimited)",
+        &["device", "namespace"],
+    )?;
+    let zns_max_active_zones = register_gauge_vec(
+        &registry,
+        "nvme_zns_max_active_zones",
+        "Maximum number of active zones (absent when unlimited)",
+        &["device", "namespace"],
+    )?;
+    let zns_zones_by_state = register_gauge_vec(
+        &registry,
+        "nvme_zns_zones_by_state",
+        "Number of zones in each zone state",
+        &["device", "namespace", "state"],
+    )?;
+    let namespace_info = register_gauge_vec(
+        &registry,
+        "nvme_namespace_info",
+        "Namespace format and identifier information",
+        &[
+            "device",
+            "namespace",
+            "nsid",
+            "lba_format",
+            "metadata_size",
+            "metadata_extended",
+            "relative_performance",
+            "protection_type",
+            "shared",
+            "nguid",
+            "eui64",
+            "uuid",
+            "csi",
+        ],
+    )?;
+
+    let ocp_info = register_gauge_vec(
+        &registry,
+        "nvme_ocp_info",
+        "OCP SMART / health log specification and log page version",
+        &["device", "specification_version", "log_page_version"],
+    )?;
+    let ocp_physical_media_written_bytes_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_physical_media_written_bytes_total",
+        "Bytes written to the NAND media, including write amplification",
+        &["device"],
+    )?;
+    let ocp_physical_media_read_bytes_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_physical_media_read_bytes_total",
+        "Bytes read from the NAND media",
+        &["device"],
+    )?;
+    let ocp_bad_nand_blocks = register_gauge_vec(
+        &registry,
+        "nvme_ocp_bad_nand_blocks",
+        "Bad NAND block count by block type",
+        &["device", "type"],
+    )?;
+    let ocp_bad_nand_blocks_normalized = register_gauge_vec(
+        &registry,
+        "nvme_ocp_bad_nand_blocks_normalized",
+        "Normalized bad NAND block value by block type",
+        &["device", "type"],
+    )?;
+    let ocp_xor_recovery_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_xor_recovery_total",
+        "Reads recovered using XOR parity",
+        &["device"],
+    )?;
+    let ocp_uncorrectable_read_errors_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_uncorrectable_read_errors_total",
+        "Reads that could not be corrected",
+        &["device"],
+    )?;
+    let ocp_soft_ecc_errors_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_soft_ecc_errors_total",
+        "Reads corrected by soft-decision ECC",
+        &["device"],
+    )?;
+    let ocp_end_to_end_errors_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_end_to_end_errors_total",
+        "End-to-end data path errors by outcome",
+        &["device", "outcome"],
+    )?;
+    let ocp_system_data_used_ratio = register_gauge_vec(
+        &registry,
+        "nvme_ocp_system_data_used_ratio",
+        "Portion of system data endurance consumed",
+        &["device"],
+    )?;
+    let ocp_refresh_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_refresh_total",
+        "NAND refresh (read disturb / retention) operations",
+        &["device"],
+    )?;
+    let ocp_user_data_erase_count = register_gauge_vec(
+        &registry,
+        "nvme_ocp_user_data_erase_count",
+        "Maximum and minimum erase count across user data blocks",
+        &["device", "bound"],
+    )?;
+    let ocp_thermal_throttling_events_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_thermal_throttling_events_total",
+        "Thermal throttling events",
+        &["device"],
+    )?;
+    let ocp_thermal_throttling_status = register_gauge_vec(
+        &registry,
+        "nvme_ocp_thermal_throttling_status",
+        "Current thermal throttling level (0 when not throttling)",
+        &["device", "level"],
+    )?;
+    let ocp_pcie_correctable_errors_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_pcie_correctable_errors_total",
+        "PCIe correctable errors",
+        &["device"],
+    )?;
+    let ocp_incomplete_shutdowns_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_incomplete_shutdowns_total",
+        "Shutdowns that did not complete normally",
+        &["device"],
+    )?;
+    let ocp_free_blocks_ratio = register_gauge_vec(
+        &registry,
+        "nvme_ocp_free_blocks_ratio",
+        "Portion of free blocks remaining",
+        &["device"],
+    )?;
+    let ocp_capacitor_health_ratio = register_gauge_vec(
+        &registry,
+        "nvme_ocp_capacitor_health_ratio",
+        "Power-loss protection capacitor health",
+        &["device"],
+    )?;
+    let ocp_unaligned_io_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_unaligned_io_total",
+        "Unaligned I/O commands",
+        &["device"],
+    )?;
+    let ocp_plp_start_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_plp_start_total",
+        "Power-loss protection events",
+        &["device"],
+    )?;
+    let ocp_endurance_estimate_bytes = register_gauge_vec(
+        &registry,
+        "nvme_ocp_endurance_estimate_bytes",
+        "Estimated total bytes that can be written over the device lifetime",
+        &["device"],
+    )?;
+    let ocp_pcie_link_retraining_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_pcie_link_retraining_total",
+        "PCIe link retraining events",
+        &["device"],
+    )?;
+    let ocp_power_state_changes_total = register_counter_vec(
+        &registry,
+        "nvme_ocp_power_state_changes_total",
+        "Power state changes",
+        &["device"],
+    )?;
+    let ocp_latency_monitor_enabled = register_gauge_vec(
+        &registry,
+        "nvme_ocp_latency_monitor_enabled",
+        "Whether the OCP latency monitor feature is enabled",
+        &["device"],
+    )?;
+    let ocp_latency_monitor_bucket_lower_bound_seconds = register_gauge_vec(
+        &registry,
+        "nvme_ocp_latency_monitor_bucket_lower_bound_seconds",
+        "Lower latency bound of each OCP latency monitor bucket",
+        &["device", "bucket"],
+    )?;
+    let ocp_latency_monitor_bucket_commands = register_gauge_vec(
+        &registry,
+        "nvme_ocp_latency_monitor_bucket_commands",
+        "Commands whose device-internal latency fell into the bucket during the window",
+        &["device", "window", "operation", "bucket"],
+    )?;
+    let ocp_latency_monitor_max_latency_seconds = register_gauge_vec(
+        &registry,
+        "nvme_ocp_latency_monitor_max_latency_seconds",
+        "Highest latency measured in the bucket",
+        &["device", "window", "operation", "bucket"],
+    )?;
+    let ocp_latency_monitor_max_latency_timestamp_seconds = register_gauge_vec(
+        &registry,
+        "nvme_ocp_latency_monitor_max_latency_timestamp_seconds",
+        "Timestamp of the highest latency measured in the bucket, by clock source",
+        &["device", "window", "operation", "bucket", "clock"],
+    )?;
+
+    let vendor_smart_normalized = register_gauge_vec(
+        &registry,
+        "nvme_vendor_smart_normalized",
+        "Normalized value of a vendor-specific SMART attribute",
+        &["device", "vendor", "attribute"],
+    )?;
+    let vendor_program_fail_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_program_fail_total",
+        "NAND program failures",
+        &["device", "vendor"],
+    )?;
+    let vendor_erase_fail_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_erase_fail_total",
+        "NAND erase failures",
+        &["device", "vendor"],
+    )?;
+    let vendor_wear_leveling_erase_count = register_gauge_vec(
+        &registry,
+        "nvme_vendor_wear_leveling_erase_count",
+        "Minimum, maximum and average NAND block erase count",
+        &["device", "vendor", "stat"],
+    )?;
+    let vendor_end_to_end_errors_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_end_to_end_errors_total",
+        "End-to-end data path errors detected",
+        &["device", "vendor"],
+    )?;
+    let vendor_crc_errors_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_crc_errors_total",
+        "PCIe interface CRC errors",
+        &["device", "vendor"],
+    )?;
+    let vendor_thermal_throttle_ratio = register_gauge_vec(
+        &registry,
+        "nvme_vendor_thermal_throttle_ratio",
+        "Current thermal throttling level",
+        &["device", "vendor"],
+    )?;
+    let vendor_thermal_throttle_events_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_thermal_throttle_events_total",
+        "Thermal throttling events",
+        &["device", "vendor"],
+    )?;
+    let vendor_retry_buffer_overflows_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_retry_buffer_overflows_total",
+        "PCIe retry buffer overflows",
+        &["device", "vendor"],
+    )?;
+    let vendor_pll_lock_loss_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_pll_lock_loss_total",
+        "PCIe PLL lock losses",
+        &["device", "vendor"],
+    )?;
+    let vendor_nand_written_bytes_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_nand_written_bytes_total",
+        "Bytes written to NAND",
+        &["device", "vendor"],
+    )?;
+    let vendor_host_written_bytes_total = register_counter_vec(
+        &registry,
+        "nvme_vendor_host_written_bytes_total",
+        "Bytes written by the host",
+        &["device", "vendor"],
+    )?;
 
     let device_accessible = register_gauge_vec(
         &registry,
@@ -271,6 +900,12 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
         "Largest error count found in log page 0x01",
         &["device"],
     )?;
+    let error_log_entry_status = register_gauge_vec(
+        &registry,
+        "nvme_error_log_entry_status",
+        "Number of entries in log page 0x01 by completion status",
+        &["device", "status"],
+    )?;
     let self_test_current_operation = register_gauge_vec(
         &registry,
         "nvme_self_test_current_operation",
@@ -284,6 +919,157 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
         &["device"],
     )?;
 
+    let command_errors_total = register_counter_vec(
+        &registry,
+        "nvme_exporter_command_errors_total",
+        "Admin commands rejected by the controller with a non-zero NVMe status",
+        &["device", "opcode", "lid", "status"],
+    )?;
+
+    let self_test_last_result = register_gauge_vec(
+        &registry,
+        "nvme_self_test_last_result",
+        "Result code of the most recent self-test of each type from log page 0x06",
+        &["device", "type"],
+    )?;
+    let self_test_last_power_on_hours = register_gauge_vec(
+        &registry,
+        "nvme_self_test_last_power_on_hours",
+        "Power on hours when the most recent self-test of each type completed",
+        &["device", "type"],
+    )?;
+    let self_test_failures_total = register_counter_vec(
+        &registry,
+        "nvme_self_test_failures_total",
+        "Failed self-test results seen in log page 0x06",
+        &["device", "type"],
+    )?;
+
+    let self_test_scheduler_total = register_counter_vec(
+        &registry,
+        "nvme_exporter_self_test_scheduler_total",
+        "Scheduled self-test runs by outcome (scheduled, started, skipped, unsupported, failed)",
+        &["device", "type", "outcome"],
+    )?;
+
+    let firmware_slot_info = register_gauge_vec(
+        &registry,
+        "nvme_firmware_slot_info",
+        "Firmware revision stored in each slot from log page 0x03",
+        &["device", "slot", "revision", "active", "pending"],
+    )?;
+
+    let persistent_event_log_events = register_gauge_vec(
+        &registry,
+        "nvme_persistent_event_log_events",
+        "Events currently held in the persistent event log (log page 0x0D)",
+        &["device"],
+    )?;
+    let persistent_event_log_bytes = register_gauge_vec(
+        &registry,
+        "nvme_persistent_event_log_bytes",
+        "Total length of the persistent event log in bytes",
+        &["device"],
+    )?;
+    let persistent_event_log_generation = register_gauge_vec(
+        &registry,
+        "nvme_persistent_event_log_generation",
+        "Persistent event log generation number",
+        &["device"],
+    )?;
+    let persistent_events_total = register_counter_vec(
+        &registry,
+        "nvme_persistent_events_total",
+        "Persistent event log events observed by type",
+        &["device", "type"],
+    )?;
+
+    let telemetry_controller_data_available = register_gauge_vec(
+        &registry,
+        "nvme_telemetry_controller_data_available",
+        "Whether the controller has saved controller-initiated telemetry data (log page 0x08)",
+        &["device"],
+    )?;
+    let telemetry_controller_data_generation = register_gauge_vec(
+        &registry,
+        "nvme_telemetry_controller_data_generation",
+        "Controller-initiated telemetry data generation number",
+        &["device"],
+    )?;
+
+    let endurance_group_critical_warning = register_gauge_vec(
+        &registry,
+        "nvme_endurance_group_critical_warning",
+        "Endurance group critical warning bitfield",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_available_spare_ratio = register_gauge_vec(
+        &registry,
+        "nvme_endurance_group_available_spare_ratio",
+        "Endurance group available spare ratio",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_available_spare_threshold_ratio = register_gauge_vec(
+        &registry,
+        "nvme_endurance_group_available_spare_threshold_ratio",
+        "Endurance group available spare threshold ratio",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_percentage_used_ratio = register_gauge_vec(
+        &registry,
+        "nvme_endurance_group_percentage_used_ratio",
+        "Endurance group percentage used ratio, can be greater than 1.0",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_endurance_estimate_bytes = register_gauge_vec(
+        &registry,
+        "nvme_endurance_group_endurance_estimate_bytes",
+        "Estimated bytes that can be written to the endurance group over its lifetime",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_data_units_read_total = register_counter_vec(
+        &registry,
+        "nvme_endurance_group_data_units_read_total",
+        "Data units read from the endurance group",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_data_units_written_total = register_counter_vec(
+        &registry,
+        "nvme_endurance_group_data_units_written_total",
+        "Data units written to the endurance group",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_media_units_written_total = register_counter_vec(
+        &registry,
+        "nvme_endurance_group_media_units_written_total",
+        "Media units written to the endurance group",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_host_read_commands_total = register_counter_vec(
+        &registry,
+        "nvme_endurance_group_host_read_commands_total",
+        "Read commands completed by the endurance group",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_host_write_commands_total = register_counter_vec(
+        &registry,
+        "nvme_endurance_group_host_write_commands_total",
+        "Write commands completed by the endurance group",
+        &["device", "endurance_group"],
+    )?;
+    let endurance_group_media_errors_total = register_counter_vec(
+        &registry,
+        "nvme_endurance_group_media_errors_total",
+        "Media and data integrity errors in the endurance group",
+        &["device", "endurance_group"],
+    )?;
+
+    let kernel_default_ps_max_latency_seconds = register_gauge(
+        &registry,
+        "nvme_kernel_default_ps_max_latency_seconds",
+        "nvme_core default_ps_max_latency_us kernel parameter in seconds",
+    )?;
+
     let scrape_duration = register_gauge(
         &registry,
         "nvme_exporter_scrape_duration_seconds",
@@ -313,6 +1099,159 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
             .with_label_values(&[&device.device])
             .set(bool_to_f64(device.accessible));
 
+        if let Some(controller) = &device.controller {
+            controller_info
+                .with_label_values(&[
+                    &device.device,
+                    &format!("0x{:04x}", controller.vendor_id),
+                    &format!("0x{:04x}", controller.subsystem_vendor_id),
+                    &format!("{:06x}", controller.ieee_oui),
+                    &controller.controller_id.to_string(),
+                    &controller.version_string(),
+                    &controller.subsystem_nqn,
+                    &format!("0x{:04x}", controller.oacs),
+                    &format!("0x{:02x}", controller.lpa),
+                    &format!("0x{:04x}", controller.oncs),
+                ])
+                .set(1.0);
+            if let Some(warning) = controller.warning_temperature_celsius() {
+                temperature_threshold_celsius
+                    .with_label_values(&[&device.device, "composite", "warning"])
+                    .set(warning);
+            }
+            if let Some(critical) = controller.critical_temperature_celsius() {
+                temperature_threshold_celsius
+                    .with_label_values(&[&device.device, "composite", "critical"])
+                    .set(critical);
+            }
+            apst_supported
+                .with_label_values(&[&device.device])
+                .set(bool_to_f64(controller.supports_apst()));
+            write_cache_present
+                .with_label_values(&[&device.device])
+                .set(bool_to_f64(controller.volatile_write_cache_present()));
+            host_memory_buffer_preferred_bytes
+                .with_label_values(&[&device.device])
+                .set(controller.host_memory_buffer_preferred_bytes() as f64);
+            host_memory_buffer_minimum_bytes
+                .with_label_values(&[&device.device])
+                .set(controller.host_memory_buffer_minimum_bytes() as f64);
+            controller_namespaces
+                .with_label_values(&[&device.device])
+                .set(f64::from(controller.number_of_namespaces));
+            total_capacity_bytes
+                .with_label_values(&[&device.device])
+                .set(u128_to_f64(controller.tnvmcap));
+            unallocated_capacity_bytes
+                .with_label_values(&[&device.device])
+                .set(u128_to_f64(controller.unvmcap));
+
+            for (state, descriptor) in controller.power_states.iter().enumerate() {
+                let state_label = state.to_string();
+                power_state_max_watts
+                    .with_label_values(&[&device.device, &state_label])
+                    .set(descriptor.max_power_watts);
+                power_state_entry_latency_seconds
+                    .with_label_values(&[&device.device, &state_label])
+                    .set(f64::from(descriptor.entry_latency_us) / 1_000_000.0);
+                power_state_exit_latency_seconds
+                    .with_label_values(&[&device.device, &state_label])
+                    .set(f64::from(descriptor.exit_latency_us) / 1_000_000.0);
+                power_state_non_operational
+                    .with_label_values(&[&device.device, &state_label])
+                    .set(bool_to_f64(descriptor.non_operational));
+            }
+        }
+
+        if let Some(power_state) = device.power_state {
+            power_state_current
+                .with_label_values(&[&device.device])
+                .set(f64::from(power_state));
+        }
+
+        if report.collect_features {
+            if let Some(features) = &device.features {
+                if let Some(arbitration) = &features.arbitration {
+                    if let Some(burst) = arbitration.burst {
+                        feature_arbitration_burst
+                            .with_label_values(&[&device.device])
+                            .set(f64::from(burst));
+                    }
+                    for (priority, weight) in [
+                        ("low", arbitration.low_priority_weight),
+                        ("medium", arbitration.medium_priority_weight),
+                        ("high", arbitration.high_priority_weight),
+                    ] {
+                        feature_arbitration_weight
+                            .with_label_values(&[&device.device, priority])
+                            .set(f64::from(weight));
+                    }
+                }
+                if let Some(queues) = &features.number_of_queues {
+                    feature_queues
+                        .with_label_values(&[&device.device, "submission"])
+                        .set(f64::from(queues.submission_queues));
+                    feature_queues
+                        .with_label_values(&[&device.device, "completion"])
+                        .set(f64::from(queues.completion_queues));
+                }
+                for entry in &features.temperature_thresholds {
+                    let Some(celsius) = entry.threshold.celsius() else {
+                        continue;
+                    };
+                    let sensor_label = if entry.sensor == 0 {
+                        "composite".to_string()
+                    } else {
+                        entry.sensor.to_string()
+                    };
+                    let kind = if entry.under { "under" } else { "over" };
+                    temperature_threshold_celsius
+                        .with_label_values(&[&device.device, &sensor_label, kind])
+                        .set(celsius);
+                }
+                if let Some(apst) = &features.apst {
+                    apst_enabled
+                        .with_label_values(&[&device.device])
+                        .set(bool_to_f64(apst.enabled));
+                    for (state, entry) in apst.entries.iter().enumerate() {
+                        if entry.idle_time_prior_to_transition_ms == 0 {
+                            continue;
+                        }
+                        let state_label = state.to_string();
+                        apst_transition_target_state
+                            .with_label_values(&[&device.device, &state_label])
+                            .set(f64::from(entry.idle_transition_power_state));
+                        apst_transition_idle_seconds
+                            .with_label_values(&[&device.device, &state_label])
+                            .set(f64::from(entry.idle_time_prior_to_transition_ms) / 1000.0);
+                    }
+                }
+                if let Some(write_cache) = &features.volatile_write_cache {
+                    write_cache_enabled
+                        .with_label_values(&[&device.device])
+                        .set(bool_to_f64(write_cache.enabled));
+                }
+                if let Some(host_memory_buffer) = &features.host_memory_buffer {
+                    host_memory_buffer_enabled
+                        .with_label_values(&[&device.device])
+                        .set(bool_to_f64(host_memory_buffer.enabled));
+                    let allocated = if host_memory_buffer.enabled {
+                        host_memory_buffer.size_bytes()
+                    } else {
+                        0
+                    };
+                    host_memory_buffer_bytes
+                        .with_label_values(&[&device.device])
+                        .set(allocated as f64);
+                }
+                if let Some(timestamp) = &features.timestamp {
+                    controller_timestamp_seconds
+                        .with_label_values(&[&device.device])
+                        .set(timestamp.milliseconds as f64 / 1000.0);
+                }
+            }
+        }
+
         if let Some(smart) = &device.smart {
             critical_warning
                 .with_label_values(&[&device.device])
@@ -414,15 +1353,121 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
 
         if report.collect_namespace {
             for namespace in &device.namespaces {
+                let identify = &namespace.identify;
+                namespace_active
+                    .with_label_values(&[&device.device, &namespace.namespace])
+                    .set(bool_to_f64(namespace.active));
+                namespace_block_device
+                    .with_label_values(&[&device.device, &namespace.namespace])
+                    .set(bool_to_f64(namespace.block_device));
                 namespace_size
                     .with_label_values(&[&device.device, &namespace.namespace])
-                    .set(namespace.nsze as f64);
+                    .set(identify.nsze as f64);
                 namespace_capacity
                     .with_label_values(&[&device.device, &namespace.namespace])
-                    .set(namespace.ncap as f64);
+                    .set(identify.ncap as f64);
                 namespace_utilization
                     .with_label_values(&[&device.device, &namespace.namespace])
-                    .set(namespace.nuse as f64);
+                    .set(identify.nuse as f64);
+                namespace_nvm_capacity_bytes
+                    .with_label_values(&[&device.device, &namespace.namespace])
+                    .set(u128_to_f64(identify.nvmcap));
+                if let Some(lba_size) = identify.lba_size_bytes() {
+                    namespace_lba_size_bytes
+                        .with_label_values(&[&device.device, &namespace.namespace])
+                        .set(lba_size as f64);
+                }
+                if let Some(size) = identify.size_bytes() {
+                    namespace_size_bytes
+                        .with_label_values(&[&device.device, &namespace.namespace])
+                        .set(size as f64);
+                }
+                if let Some(capacity) = identify.capacity_bytes() {
+                    namespace_capacity_bytes
+                        .with_label_values(&[&device.device, &namespace.namespace])
+                        .set(capacity as f64);
+                }
+                if let Some(utilization) = identify.utilization_bytes() {
+                    namespace_utilization_bytes
+                        .with_label_values(&[&device.device, &namespace.namespace])
+                        .set(utilization as f64);
+                }
+                if let Some(smart) = &namespace.smart {
+                    namespace_data_units_read_total
+                        .with_label_values(&[&device.device, &namespace.namespace])
+                        .inc_by(u128_to_f64(smart.data_units_read));
+                    namespace_data_units_written_total
+                        .with_label_values(&[&device.device, &namespace.namespace])
+                        .inc_by(u128_to_f64(smart.data_units_written));
+                    namespace_host_read_commands_total
+                        .with_label_values(&[&device.device, &namespace.namespace])
+                        .inc_by(u128_to_f64(smart.host_read_commands));
+                    namespace_host_write_commands_total
+                        .with_label_values(&[&device.device, &namespace.namespace])
+                        .inc_by(u128_to_f64(smart.host_write_commands));
+                }
+                if let Some(zoned) = &namespace.zoned {
+                    if let Some(zone_size) = zoned.identify.zone_size_bytes(identify) {
+                        zns_zone_size_bytes
+                            .with_label_values(&[&device.device, &namespace.namespace])
+                            .set(zone_size as f64);
+                    }
+                    if let Some(zones) = zoned.identify.zone_count(identify) {
+                        zns_zones
+                            .with_label_values(&[&device.device, &namespace.namespace])
+                            .set(zones as f64);
+                    }
+                    if let Some(limit) = zoned.identify.max_open_resources {
+                        zns_max_open_zones
+                            .with_label_values(&[&device.device, &namespace.namespace])
+                            .set(f64::from(limit));
+                    }
+                    if let Some(limit) = zoned.identify.max_active_resources {
+                        zns_max_active_zones
+                            .with_label_values(&[&device.device, &namespace.namespace])
+                            .set(f64::from(limit));
+                    }
+                    for (state, count) in &zoned.zones_by_state {
+                        zns_zones_by_state
+                            .with_label_values(&[
+                                &device.device,
+                                &namespace.namespace,
+                                state.name(),
+                            ])
+                            .set(*count as f64);
+                    }
+                }
+                let format = identify.formatted_lba_format();
+                let descriptors = namespace.descriptors.unwrap_or_default();
+                let nguid = descriptors
+                    .nguid_string()
+                    .or_else(|| identify.nguid_string())
+                    .unwrap_or_default();
+                let eui64 = descriptors
+                    .eui64_string()
+                    .or_else(|| identify.eui64_string())
+                    .unwrap_or_default();
+                namespace_info
+                    .with_label_values(&[
+                        &device.device,
+                        &namespace.namespace,
+                        &namespace.nsid.to_string(),
+                        &identify.formatted_lba_index().to_string(),
+                        &format
+                            .map(|value| value.metadata_size.to_string())
+                            .unwrap_or_default(),
+                        bool_label(identify.metadata_extended_lba()),
+                        format
+                            .map(|value| value.relative_performance_name())
+                            .unwrap_or_default(),
+                        identify.protection_type_name(),
+                        bool_label(identify.shared()),
+                        &nguid,
+                        &eui64,
+                        &descriptors.uuid_string().unwrap_or_default(),
+                        &descriptors.csi_name().unwrap_or_else(|| "nvm".to_string()),
+                    ])
+                    .set(1.0);
             }
         }
 
@@ -434,6 +1479,16 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
                 error_log_max_error_count
                     .with_label_values(&[&device.device])
                     .set(error_log.max_error_count as f64);
+
+                let mut status_counts = BTreeMap::<String, u64>::new();
+                for entry in &error_log.entries {
+                    *status_counts.entry(entry.status.label()).or_insert(0) += 1;
+                }
+                for (status, count) in &status_counts {
+                    error_log_entry_status
+                        .with_label_values(&[&device.device, status])
+                        .set(*count as f64);
+                }
             }
         }
 
@@ -445,14 +1500,363 @@ pub fn encode_report(report: &ScrapeReport) -> Result<String, NvmeError> {
                 self_test_current_completion_ratio
                     .with_label_values(&[&device.device])
                     .set(self_test.current_completion_ratio);
+
+                let mut seen_types = Vec::<String>::new();
+                for result in &self_test.results {
+                    let test_type = result.operation_name();
+                    if seen_types.contains(&test_type) {
+                        continue;
+                    }
+                    self_test_last_result
+                        .with_label_values(&[&device.device, &test_type])
+                        .set(f64::from(result.result));
+                    self_test_last_power_on_hours
+                        .with_label_values(&[&device.device, &test_type])
+                        .set(result.power_on_hours as f64);
+                    seen_types.push(test_type);
+                }
+                for test_type in &seen_types {
+                    self_test_failures_total.with_label_values(&[&device.device, test_type]);
+                }
+                for (test_type, count) in &self_test.failure_totals {
+                    self_test_failures_total
+                        .with_label_values(&[&device.device, test_type])
+                        .inc_by(*count as f64);
+                }
             }
         }
+
+        if report.collect_vendor_logs {
+            if let Some(ocp) = device
+                .vendor_logs
+                .as_ref()
+                .and_then(|value| value.ocp_smart.as_ref())
+            {
+                ocp_info
+                    .with_label_values(&[
+                        &device.device,
+                        &ocp.specification_version,
+                        &ocp.log_page_version.to_string(),
+                    ])
+                    .set(1.0);
+                ocp_physical_media_written_bytes_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(u128_to_f64(ocp.physical_media_units_written));
+                ocp_physical_media_read_bytes_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(u128_to_f64(ocp.physical_media_units_read));
+                for (block_type, raw, normalized) in [
+                    (
+                        "user",
+                        ocp.bad_user_nand_blocks,
+                        ocp.bad_user_nand_blocks_normalized,
+                    ),
+                    (
+                        "system",
+                        ocp.bad_system_nand_blocks,
+                        ocp.bad_system_nand_blocks_normalized,
+                    ),
+                ] {
+                    ocp_bad_nand_blocks
+                        .with_label_values(&[&device.device, block_type])
+                        .set(raw as f64);
+                    ocp_bad_nand_blocks_normalized
+                        .with_label_values(&[&device.device, block_type])
+                        .set(f64::from(normalized));
+                }
+                ocp_xor_recovery_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(ocp.xor_recovery_count as f64);
+                ocp_uncorrectable_read_errors_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(ocp.uncorrectable_read_errors as f64);
+                ocp_soft_ecc_errors_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(ocp.soft_ecc_errors as f64);
+                ocp_end_to_end_errors_total
+                    .with_label_values(&[&device.device, "detected"])
+                    .inc_by(f64::from(ocp.end_to_end_detected_errors));
+                ocp_end_to_end_errors_total
+                    .with_label_values(&[&device.device, "corrected"])
+                    .inc_by(f64::from(ocp.end_to_end_corrected_errors));
+                ocp_system_data_used_ratio
+                    .with_label_values(&[&device.device])
+                    .set(f64::from(ocp.system_data_percent_used) / 100.0);
+                ocp_refresh_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(ocp.refresh_count as f64);
+                ocp_user_data_erase_count
+                    .with_label_values(&[&device.device, "max"])
+                    .set(f64::from(ocp.max_user_data_erase_count));
+                ocp_user_data_erase_count
+                    .with_label_values(&[&device.device, "min"])
+                    .set(f64::from(ocp.min_user_data_erase_count));
+                ocp_thermal_throttling_events_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(f64::from(ocp.thermal_throttling_events));
+                ocp_thermal_throttling_status
+                    .with_label_values(&[&device.device, ocp.thermal_throttling_status_name()])
+                    .set(f64::from(ocp.thermal_throttling_status));
+                ocp_pcie_correctable_errors_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(ocp.pcie_correctable_errors as f64);
+                ocp_incomplete_shutdowns_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(f64::from(ocp.incomplete_shutdowns));
+                ocp_free_blocks_ratio
+                    .with_label_values(&[&device.device])
+                    .set(f64::from(ocp.percent_free_blocks) / 100.0);
+                ocp_capacitor_health_ratio
+                    .with_label_values(&[&device.device])
+                    .set(f64::from(ocp.capacitor_health) / 100.0);
+                ocp_unaligned_io_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(ocp.unaligned_io as f64);
+                ocp_plp_start_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(u128_to_f64(ocp.plp_start_count));
+                ocp_endurance_estimate_bytes
+                    .with_label_values(&[&device.device])
+                    .set(u128_to_f64(ocp.endurance_estimate));
+                ocp_pcie_link_retraining_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(ocp.pcie_link_retraining_count as f64);
+                ocp_power_state_changes_total
+                    .with_label_values(&[&device.device])
+                    .inc_by(ocp.power_state_change_count as f64);
+            }
+
+            if let Some(latency) = device
+                .vendor_logs
+                .as_ref()
+                .and_then(|value| value.ocp_latency_monitor.as_ref())
+            {
+                ocp_latency_monitor_enabled
+                    .with_label_values(&[&device.device])
+                    .set(bool_to_f64(latency.enabled()));
+                for (window_label, window) in [
+                    ("active", &latency.active),
+                    ("static", &latency.static_window),
+                ] {
+                    for (index, bucket) in window.buckets.iter().enumerate() {
+                        let bucket_label = index.to_string();
+                        if window_label == "active" {
+                            if let Some(bound_ms) = latency.bucket_lower_bound_ms(index) {
+                                ocp_latency_monitor_bucket_lower_bound_seconds
+                                    .with_label_values(&[&device.device, &bucket_label])
+                                    .set(bound_ms as f64 / 1000.0);
+                            }
+                        }
+                        for operation in LatencyOperation::ALL {
+                            let labels = [
+                                device.device.as_str(),
+                                window_label,
+                                operation.label(),
+                                bucket_label.as_str(),
+                            ];
+                            ocp_latency_monitor_bucket_commands
+                                .with_label_values(&labels)
+                                .set(f64::from(bucket.commands(operation)));
+                            ocp_latency_monitor_max_latency_seconds
+                                .with_label_values(&labels)
+                                .set(f64::from(bucket.max_latency_ms(operation)) / 1000.0);
+                            let timestamp_ms = bucket.max_latency_timestamp_ms(operation);
+                            if timestamp_ms != 0 && timestamp_ms != u64::MAX {
+                                let clock = if window.host_timestamp(index, operation) {
+                                    "host"
+                                } else {
+                                    "power_on"
+                                };
+                                ocp_latency_monitor_max_latency_timestamp_seconds
+                                    .with_label_values(&[
+                                        &device.device,
+                                        window_label,
+                                        operation.label(),
+                                        &bucket_label,
+                                        clock,
+                                    ])
+                                    .set(timestamp_ms as f64 / 1000.0);
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(intel) = device
+                .vendor_logs
+                .as_ref()
+                .and_then(|value| value.intel_smart.as_ref())
+            {
+                let labels = [device.device.as_str(), "intel"];
+                for (attribute, normalized) in intel.normalized_values() {
+                    vendor_smart_normalized
+                        .with_label_values(&[&device.device, "intel", attribute])
+                        .set(f64::from(normalized));
+                }
+                for (metric, attribute) in [
+                    (&vendor_program_fail_total, intel.program_fail),
+                    (&vendor_erase_fail_total, intel.erase_fail),
+                    (
+                        &vendor_end_to_end_errors_total,
+                        intel.end_to_end_error_detect,
+                    ),
+                    (&vendor_crc_errors_total, intel.crc_error),
+                    (
+                        &vendor_retry_buffer_overflows_total,
+                        intel.retry_buffer_overflow,
+                    ),
+                    (&vendor_pll_lock_loss_total, intel.pll_lock_loss),
+                ] {
+                    if let Some(attribute) = attribute {
+                        metric
+                            .with_label_values(&labels)
+                            .inc_by(attribute.raw as f64);
+                    }
+                }
+                if let Some(wear_leveling) = intel.wear_leveling {
+                    for (stat, value) in [
+                        ("min", wear_leveling.min),
+                        ("max", wear_leveling.max),
+                        ("avg", wear_leveling.avg),
+                    ] {
+                        vendor_wear_leveling_erase_count
+                            .with_label_values(&[&device.device, "intel", stat])
+                            .set(f64::from(value));
+                    }
+                }
+                if let Some(thermal_throttle) = intel.thermal_throttle {
+                    vendor_thermal_throttle_ratio
+                        .with_label_values(&labels)
+                        .set(f64::from(thermal_throttle.percent) / 100.0);
+                    vendor_thermal_throttle_events_total
+                        .with_label_values(&labels)
+                        .inc_by(f64::from(thermal_throttle.count));
+                }
+                if let Some(bytes) = intel.nand_written_bytes() {
+                    vendor_nand_written_bytes_total
+                        .with_label_values(&labels)
+                        .inc_by(bytes as f64);
+                }
+                if let Some(bytes) = intel.host_written_bytes() {
+                    vendor_host_written_bytes_total
+                        .with_label_values(&labels)
+                        .inc_by(bytes as f64);
+                }
+            }
+        }
+
+        if let Some(firmware_slots) = &device.firmware_slots {
+            for slot in &firmware_slots.slots {
+                let active = slot.slot == firmware_slots.active_slot;
+                let pending = firmware_slots.pending_activation()
+                    && firmware_slots.next_reset_slot == Some(slot.slot);
+                firmware_slot_info
+                    .with_label_values(&[
+                        &device.device,
+                        &slot.slot.to_string(),
+                        &slot.revision,
+                        bool_label(active),
+                        bool_label(pending),
+                    ])
+                    .set(1.0);
+            }
+        }
+
+        for group in &device.endurance_groups {
+            let id = group.id.to_string();
+            let labels = [device.device.as_str(), id.as_str()];
+            let log = &group.log;
+            endurance_group_critical_warning
+                .with_label_values(&labels)
+                .set(f64::from(log.critical_warning));
+            endurance_group_available_spare_ratio
+                .with_label_values(&labels)
+                .set(log.available_spare_ratio());
+            endurance_group_available_spare_threshold_ratio
+                .with_label_values(&labels)
+                .set(log.available_spare_threshold_ratio());
+            endurance_group_percentage_used_ratio
+                .with_label_values(&labels)
+                .set(log.percent_used_ratio());
+            endurance_group_endurance_estimate_bytes
+                .with_label_values(&labels)
+                .set(log.endurance_estimate_bytes());
+            endurance_group_data_units_read_total
+                .with_label_values(&labels)
+                .inc_by(u128_to_f64(log.data_units_read));
+            endurance_group_data_units_written_total
+                .with_label_values(&labels)
+                .inc_by(u128_to_f64(log.data_units_written));
+            endurance_group_media_units_written_total
+                .with_label_values(&labels)
+                .inc_by(u128_to_f64(log.media_units_written));
+            endurance_group_host_read_commands_total
+                .with_label_values(&labels)
+                .inc_by(u128_to_f64(log.host_read_commands));
+            endurance_group_host_write_commands_total
+                .with_label_values(&labels)
+                .inc_by(u128_to_f64(log.host_write_commands));
+            endurance_group_media_errors_total
+                .with_label_values(&labels)
+                .inc_by(u128_to_f64(log.media_errors));
+        }
+
+        if let Some(telemetry) = &device.telemetry {
+            telemetry_controller_data_available
+                .with_label_values(&[&device.device])
+                .set(bool_to_f64(telemetry.controller_data_available));
+            telemetry_controller_data_generation
+                .with_label_values(&[&device.device])
+                .set(f64::from(telemetry.controller_generation_number));
+        }
+
+        if report.collect_persistent_event_log {
+            if let Some(persistent_events) = &device.persistent_events {
+                persistent_event_log_events
+                    .with_label_values(&[&device.device])
+                    .set(f64::from(persistent_events.total_events));
+                persistent_event_log_bytes
+                    .with_label_values(&[&device.device])
+                    .set(persistent_events.total_log_length as f64);
+                persistent_event_log_generation
+                    .with_label_values(&[&device.device])
+                    .set(f64::from(persistent_events.generation_number));
+                for (event_type, count) in &persistent_events.event_totals {
+                    persistent_events_total
+                        .with_label_values(&[&device.device, event_type])
+                        .inc_by(*count as f64);
+                }
+            }
+        }
+    }
+
+    for command_error in &report.command_errors {
+        let opcode = format!("0x{:02x}", command_error.opcode);
+        let lid = command_error
+            .lid
+            .map(|value| format!("0x{:02x}", value))
+            .unwrap_or_default();
+        command_errors_total
+            .with_label_values(&[&command_error.device, &opcode, &lid, &command_error.status])
+            .inc_by(command_error.count as f64);
+    }
+
+    for schedule in &report.self_test_schedule {
+        self_test_scheduler_total
+            .with_label_values(&[&schedule.device, &schedule.test_type, &schedule.outcome])
+            .inc_by(schedule.count as f64);
+    }
+
+    if let Some(latency_us) = report.kernel_default_ps_max_latency_us {
+        kernel_default_ps_max_latency_seconds.set(latency_us as f64 / 1_000_000.0);
     }
 
     scrape_duration.set(report.duration_seconds);
     scrape_success.set(bool_to_f64(report.success));
     device_count.set(report.discovered_device_count as f64);
 
+    register_custom_log_metrics(&registry, report);
+
     let metric_families = registry.gather();
     let mut buffer = Vec::<u8>::new();
     TextEncoder::new()
@@ -500,6 +1904,66 @@ fn register_counter_vec(
     Ok(metric)
 }
 
+fn register_custom_log_metrics(registry: &Registry, report: &ScrapeReport) {
+    if !report.collect_vendor_logs {
+        return;
+    }
+
+    let mut gauges = BTreeMap::<String, GaugeVec>::new();
+    let mut counters = BTreeMap::<String, CounterVec>::new();
+    let mut skipped = BTreeSet::<String>::new();
+    for device in &report.devices {
+        let Some(vendor_logs) = &device.vendor_logs else {
+            continue;
+        };
+        for log in &vendor_logs.custom {
+            for value in &log.values {
+                if skipped.contains(&value.metric) {
+                    continue;
+                }
+                let labels = [device.device.as_str(), log.name.as_str()];
+                let registered = match value.kind {
+                    MetricKind::Gauge => match gauges.get(&value.metric) {
+                        Some(metric) => Ok(metric.clone()),
+                        None => register_gauge_vec(
+                            registry,
+                            &value.metric,
+                            &value.help,
+                            &["device", "log_page"],
+                        )
+                        .inspect(|metric| {
+                            gauges.insert(value.metric.clone(), metric.clone());
+                        }),
+                    }
+                    .map(|metric| metric.with_label_values(&labels).set(value.value)),
+                    MetricKind::Counter => match counters.get(&value.metric) {
+                        Some(metric) => Ok(metric.clone()),
+                        None => register_counter_vec(
+                            registry,
+                            &value.metric,
+                            &value.help,
+                            &["device", "log_page"],
+                        )
+                        .inspect(|metric| {
+                            counters.insert(value.metric.clone(), metric.clone());
+                        }),
+                    }
+                    .map(|metric| metric.with_label_values(&labels).inc_by(value.value)),
+                };
+                if let Err(error) = registered {
+                    warn!(
+                        metric = %value.metric,
+                        log_page = %log.name,
+                        error = %error,
+                        "skipping custom log page field"
+                    );
+                    skipped.insert(value.metric.clone());
+                }
+            }
+        }
+    }
+}
+
 fn bool_to_f64(value: bool) -> f64 {
     if value {
         1.0
@@ -508,6 +1972,14 @@ fn bool_to_f64(value: bool) -> f64 {
     }
 }
 
+fn bool_label(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
+}
+
 fn u128_to_f64(value: u128) -> f64 {
     value as f64
 }
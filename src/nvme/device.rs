@@ -6,22 +6,84 @@ use std::path::PathBuf;
 
 use crate::nvme::error::NvmeError;
 use crate::nvme::ioctl;
+use crate::nvme::ioctl::FeatureSelect;
+use crate::nvme::ioctl::GetFeatures;
+use crate::nvme::ioctl::GetLogPage;
+use crate::nvme::types::Arbitration;
+use crate::nvme::types::AutonomousPowerStateTransition;
+use crate::nvme::types::EnduranceGroupList;
+use crate::nvme::types::EnduranceGroupLog;
 use crate::nvme::types::ErrorLogSummary;
+use crate::nvme::types::FirmwareSlotLog;
+use crate::nvme::types::HostMemoryBuffer;
 use crate::nvme::types::IdentifyController;
 use crate::nvme::types::IdentifyNamespace;
+use crate::nvme::types::NamespaceIdList;
+use crate::nvme::types::NamespaceIdentificationDescriptors;
+use crate::nvme::types::NumberOfQueues;
+use crate::nvme::types::PersistentEventLog;
+use crate::nvme::types::PowerManagement;
 use crate::nvme::types::SelfTestLogSummary;
 use crate::nvme::types::SmartLog;
+use crate::nvme::types::TelemetryLog;
+use crate::nvme::types::TelemetryLogHeader;
+use crate::nvme::types::TemperatureThreshold;
+use crate::nvme::types::Timestamp;
+use crate::nvme::types::VolatileWriteCache;
+use crate::nvme::types::ZoneReportHeader;
+use crate::nvme::types::ZoneState;
+use crate::nvme::types::ZonedNamespace;
+use crate::nvme::types::APST_DATA_BYTES;
+use crate::nvme::types::COMMAND_SET_ZONED;
+use crate::nvme::types::ENDURANCE_GROUP_LOG_BYTES;
 use crate::nvme::types::ERROR_LOG_BYTES;
+use crate::nvme::types::FIRMWARE_SLOT_LOG_BYTES;
+use crate::nvme::types::HOST_MEMORY_BUFFER_DATA_BYTES;
+use crate::nvme::types::PERSISTENT_EVENT_LOG_HEADER_BYTES;
 use crate::nvme::types::SELF_TEST_LOG_BYTES;
 use crate::nvme::types::SMART_LOG_BYTES;
+use crate::nvme::types::TELEMETRY_BLOCK_BYTES;
+use crate::nvme::types::TIMESTAMP_DATA_BYTES;
+use crate::nvme::types::ZONE_REPORT_BYTES;
+use crate::nvme::vendor::intel::IntelSmartLog;
+use crate::nvme::vendor::intel::INTEL_SMART_LOG_BYTES;
+use crate::nvme::vendor::intel::LID_INTEL_SMART;
+use crate::nvme::vendor::ocp::OcpLatencyMonitorLog;
+use crate::nvme::vendor::ocp::OcpSmartLog;
+use crate::nvme::vendor::ocp::LID_OCP_LATENCY_MONITOR;
+use crate::nvme::vendor::ocp::LID_OCP_SMART;
+use crate::nvme::vendor::ocp::OCP_LATENCY_MONITOR_LOG_BYTES;
+use crate::nvme::vendor::ocp::OCP_SMART_LOG_BYTES;
 
 const LID_ERROR_INFORMATION: u8 = 0x01;
 const LID_SMART_HEALTH: u8 = 0x02;
+const LID_FIRMWARE_SLOT: u8 = 0x03;
 const LID_SELF_TEST: u8 = 0x06;
+const LID_ENDURANCE_GROUP_INFORMATION: u8 = 0x09;
+const LID_TELEMETRY_HOST: u8 = 0x07;
+const LID_TELEMETRY_CONTROLLER: u8 = 0x08;
+const LID_PERSISTENT_EVENT: u8 = 0x0D;
+const NSID_ALL: u32 = 0xFFFF_FFFF;
+const PERSISTENT_EVENT_READ: u8 = 0x00;
+const PERSISTENT_EVENT_ESTABLISH_CONTEXT: u8 = 0x01;
+const PERSISTENT_EVENT_RELEASE_CONTEXT: u8 = 0x02;
+const PERSISTENT_EVENT_LOG_MAX_BYTES: u64 = 16 * 1024 * 1024;
+const TELEMETRY_CREATE_HOST_DATA: u8 = 0x01;
+const TELEMETRY_LOG_MAX_BYTES: u64 = 512 * 1024 * 1024;
+const FID_ARBITRATION: u8 = 0x01;
+const FID_POWER_MANAGEMENT: u8 = 0x02;
+const FID_TEMPERATURE_THRESHOLD: u8 = 0x04;
+const FID_VOLATILE_WRITE_CACHE: u8 = 0x06;
+const FID_NUMBER_OF_QUEUES: u8 = 0x07;
+const FID_AUTONOMOUS_POWER_STATE_TRANSITION: u8 = 0x0C;
+const FID_HOST_MEMORY_BUFFER: u8 = 0x0D;
+const FID_TIMESTAMP: u8 = 0x0E;
 
 pub struct NvmeDevice {
     path: PathBuf,
     file: File,
+    max_transfer_bytes: usize,
+    extended_log_page: bool,
 }
 
 impl NvmeDevice {
@@ -34,9 +96,18 @@ impl NvmeDevice {
         Ok(Self {
             path: path.to_path_buf(),
             file,
+            max_transfer_bytes: ioctl::DEFAULT_LOG_PAGE_TRANSFER_BYTES,
+            extended_log_page: false,
         })
     }
 
+    pub fn set_max_transfer(&mut self, identify: &IdentifyController) {
+        self.max_transfer_bytes = identify
+            .max_data_transfer_bytes()
+            .unwrap_or(ioctl::MAX_LOG_PAGE_TRANSFER_BYTES);
+        self.extended_log_page = identify.supports_extended_log_page();
+    }
+
     pub fn identify_controller(&self, timeout_ms: u32) -> Result<IdentifyController, NvmeError> {
         let bytes =
             ioctl::identify_controller(self.file.as_raw_fd(), &self.path_string(), timeout_ms)?;
@@ -57,6 +128,70 @@ impl NvmeDevice {
         IdentifyNamespace::parse(&bytes)
     }
 
+    pub fn active_namespace_ids(&self, timeout_ms: u32) -> Result<Vec<u32>, NvmeError> {
+        let mut nsids = Vec::new();
+        let mut start_nsid = 0_u32;
+        loop {
+            let bytes = ioctl::identify_active_namespace_list(
+                self.file.as_raw_fd(),
+                &self.path_string(),
+                start_nsid,
+                timeout_ms,
+            )?;
+            let list = NamespaceIdList::parse(&bytes)?;
+            let full = list.full();
+            let last = list.nsids.last().copied();
+            nsids.extend(list.nsids);
+
+            match last {
+                Some(value) if full && value < NSID_ALL - 1 => start_nsid = value,
+                _ => return Ok(nsids),
+            }
+        }
+    }
+
+    pub fn namespace_descriptors(
+        &self,
+        nsid: u32,
+        timeout_ms: u32,
+    ) -> Result<NamespaceIdentificationDescriptors, NvmeError> {
+        let bytes = ioctl::identify_namespace_descriptors(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            nsid,
+            timeout_ms,
+        )?;
+        NamespaceIdentificationDescriptors::parse(&bytes)
+    }
+
+    pub fn zoned_namespace(&self, nsid: u32, timeout_ms: u32) -> Result<ZonedNamespace, NvmeError> {
+        let bytes = ioctl::identify_command_set_namespace(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            nsid,
+            COMMAND_SET_ZONED,
+            timeout_ms,
+        )?;
+        ZonedNamespace::parse(&bytes)
+    }
+
+    pub fn zones_in_state(
+        &self,
+        nsid: u32,
+        state: ZoneState,
+        timeout_ms: u32,
+    ) -> Result<u64, NvmeError> {
+        let bytes = ioctl::zone_management_receive(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            nsid,
+            state.report_filter(),
+            ZONE_REPORT_BYTES,
+            timeout_ms,
+        )?;
+        Ok(ZoneReportHeader::parse(&bytes)?.number_of_zones)
+    }
+
     pub fn smart_log(&self, timeout_ms: u32) -> Result<SmartLog, NvmeError> {
         let bytes = ioctl::get_controller_log_page(
             self.file.as_raw_fd(),
@@ -68,6 +203,57 @@ impl NvmeDevice {
         SmartLog::parse(&bytes)
     }
 
+    pub fn namespace_smart_log(&self, nsid: u32, timeout_ms: u32) -> Result<SmartLog, NvmeError> {
+        let mut request = GetLogPage::new(LID_SMART_HEALTH, SMART_LOG_BYTES);
+        request.nsid = nsid;
+        let bytes = ioctl::get_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            &request,
+            timeout_ms,
+        )?;
+        SmartLog::parse(&bytes)
+    }
+
+    pub fn endurance_group_ids(&self, timeout_ms: u32) -> Result<Vec<u16>, NvmeError> {
+        let mut ids = Vec::new();
+        let mut start_id = 0_u16;
+        loop {
+            let bytes = ioctl::identify_endurance_group_list(
+                self.file.as_raw_fd(),
+                &self.path_string(),
+                start_id,
+                timeout_ms,
+            )?;
+            let list = EnduranceGroupList::parse(&bytes)?;
+            let full = list.full();
+            let last = list.ids.last().copied();
+            ids.extend(list.ids);
+
+            match last {
+                Some(value) if full && value < u16::MAX => start_id = value + 1,
+                _ => return Ok(ids),
+            }
+        }
+    }
+
+    pub fn endurance_group_log(
+        &self,
+        endurance_group_id: u16,
+        timeout_ms: u32,
+    ) -> Result<EnduranceGroupLog, NvmeError> {
+        let mut request =
+            GetLogPage::new(LID_ENDURANCE_GROUP_INFORMATION, ENDURANCE_GROUP_LOG_BYTES);
+        request.lsi = endurance_group_id;
+        let bytes = ioctl::get_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            &request,
+            timeout_ms,
+        )?;
+        EnduranceGroupLog::parse(&bytes)
+    }
+
     pub fn error_log(&self, timeout_ms: u32) -> Result<ErrorLogSummary, NvmeError> {
         let bytes = ioctl::get_controller_log_page(
             self.file.as_raw_fd(),
@@ -79,6 +265,17 @@ impl NvmeDevice {
         ErrorLogSummary::parse(&bytes)
     }
 
+    pub fn firmware_slot_log(&self, timeout_ms: u32) -> Result<FirmwareSlotLog, NvmeError> {
+        let bytes = ioctl::get_controller_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            LID_FIRMWARE_SLOT,
+            FIRMWARE_SLOT_LOG_BYTES,
+            timeout_ms,
+        )?;
+        FirmwareSlotLog::parse(&bytes)
+    }
+
     pub fn self_test_log(&self, timeout_ms: u32) -> Result<SelfTestLogSummary, NvmeError> {
         let bytes = ioctl::get_controller_log_page(
             self.file.as_raw_fd(),
@@ -90,6 +287,284 @@ impl NvmeDevice {
         SelfTestLogSummary::parse(&bytes)
     }
 
+    pub fn log_page(
+        &self,
+        nsid: u32,
+        lid: u8,
+        data_len: usize,
+        timeout_ms: u32,
+    ) -> Result<Vec<u8>, NvmeError> {
+        let mut request = GetLogPage::new(lid, data_len);
+        request.nsid = nsid;
+        self.read_log_page(&request, timeout_ms)
+    }
+
+    fn read_log_page(&self, request: &GetLogPage, timeout_ms: u32) -> Result<Vec<u8>, NvmeError> {
+        ioctl::get_log_page_chunked(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            request,
+            self.max_transfer_bytes,
+            self.extended_log_page,
+            timeout_ms,
+        )
+    }
+
+    pub fn persistent_event_log(&self, timeout_ms: u32) -> Result<PersistentEventLog, NvmeError> {
+        let header =
+            self.persistent_event_log_context(PERSISTENT_EVENT_ESTABLISH_CONTEXT, timeout_ms)?;
+        let log = self.read_persistent_event_log(header, timeout_ms);
+        let released =
+            self.persistent_event_log_context(PERSISTENT_EVENT_RELEASE_CONTEXT, timeout_ms);
+        let log = log?;
+        released?;
+        Ok(log)
+    }
+
+    fn read_persistent_event_log(
+        &self,
+        mut bytes: Vec<u8>,
+        timeout_ms: u32,
+    ) -> Result<PersistentEventLog, NvmeError> {
+        let header = PersistentEventLog::parse(&bytes)?.header;
+        if header.total_log_length > PERSISTENT_EVENT_LOG_MAX_BYTES {
+            return Err(NvmeError::InvalidData(format!(
+                "persistent event log length {} exceeds {} bytes",
+                header.total_log_length, PERSISTENT_EVENT_LOG_MAX_BYTES
+            )));
+        }
+
+        let total_length = header.total_log_length as usize;
+        if total_length > bytes.len() {
+            let mut request = GetLogPage::new(
+                LID_PERSISTENT_EVENT,
+                (total_length - bytes.len()).next_multiple_of(4),
+            );
+            request.lsp = PERSISTENT_EVENT_READ;
+            request.offset = bytes.len() as u64;
+            bytes.extend_from_slice(&self.read_log_page(&request, timeout_ms)?);
+            bytes.truncate(total_length);
+        }
+        PersistentEventLog::parse(&bytes)
+    }
+
+    fn persistent_event_log_context(
+        &self,
+        action: u8,
+        timeout_ms: u32,
+    ) -> Result<Vec<u8>, NvmeError> {
+        let mut request = GetLogPage::new(LID_PERSISTENT_EVENT, PERSISTENT_EVENT_LOG_HEADER_BYTES);
+        request.lsp = action;
+        ioctl::get_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            &request,
+            timeout_ms,
+        )
+    }
+
+    pub fn host_telemetry_log(
+        &self,
+        data_area: usize,
+        timeout_ms: u32,
+    ) -> Result<TelemetryLog, NvmeError> {
+        let mut request = GetLogPage::new(LID_TELEMETRY_HOST, TELEMETRY_BLOCK_BYTES);
+        request.lsp = TELEMETRY_CREATE_HOST_DATA;
+        let mut data = ioctl::get_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            &request,
+            timeout_ms,
+        )?;
+        let header = TelemetryLogHeader::parse(&data)?;
+
+        let total_length = header.data_area_bytes(data_area);
+        if total_length > TELEMETRY_LOG_MAX_BYTES {
+            return Err(NvmeError::InvalidData(format!(
+                "telemetry log length {} exceeds {} bytes",
+                total_length, TELEMETRY_LOG_MAX_BYTES
+            )));
+        }
+        let total_length = total_length as usize;
+        if total_length > data.len() {
+            let mut request = GetLogPage::new(LID_TELEMETRY_HOST, total_length - data.len());
+            request.offset = data.len() as u64;
+            data.extend_from_slice(&self.read_log_page(&request, timeout_ms)?);
+        }
+        Ok(TelemetryLog { header, data })
+    }
+
+    pub fn controller_telemetry_header(
+        &self,
+        timeout_ms: u32,
+    ) -> Result<TelemetryLogHeader, NvmeError> {
+        let bytes = ioctl::get_controller_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            LID_TELEMETRY_CONTROLLER,
+            TELEMETRY_BLOCK_BYTES,
+            timeout_ms,
+        )?;
+        TelemetryLogHeader::parse(&bytes)
+    }
+
+    pub fn ocp_smart_log(&self, timeout_ms: u32) -> Result<Option<OcpSmartLog>, NvmeError> {
+        let bytes = ioctl::get_controller_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            LID_OCP_SMART,
+            OCP_SMART_LOG_BYTES,
+            timeout_ms,
+        )?;
+        if !OcpSmartLog::has_guid(&bytes) {
+            return Ok(None);
+        }
+        OcpSmartLog::parse(&bytes).map(Some)
+    }
+
+    pub fn ocp_latency_monitor_log(
+        &self,
+        timeout_ms: u32,
+    ) -> Result<Option<OcpLatencyMonitorLog>, NvmeError> {
+        let bytes = ioctl::get_controller_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            LID_OCP_LATENCY_MONITOR,
+            OCP_LATENCY_MONITOR_LOG_BYTES,
+            timeout_ms,
+        )?;
+        if !OcpLatencyMonitorLog::has_guid(&bytes) {
+            return Ok(None);
+        }
+        OcpLatencyMonitorLog::parse(&bytes).map(Some)
+    }
+
+    pub fn intel_smart_log(&self, timeout_ms: u32) -> Result<Option<IntelSmartLog>, NvmeError> {
+        let bytes = ioctl::get_controller_log_page(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            LID_INTEL_SMART,
+            INTEL_SMART_LOG_BYTES,
+            timeout_ms,
+        )?;
+        let log = IntelSmartLog::parse(&bytes)?;
+        if log.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(log))
+    }
+
+    pub fn get_features(
+        &self,
+        request: &GetFeatures,
+        timeout_ms: u32,
+    ) -> Result<(u32, Vec<u8>), NvmeError> {
+        ioctl::get_features(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            request,
+            timeout_ms,
+        )
+    }
+
+    pub fn arbitration(
+        &self,
+        select: FeatureSelect,
+        timeout_ms: u32,
+    ) -> Result<Arbitration, NvmeError> {
+        let (value, _) =
+            self.get_features(&GetFeatures::new(FID_ARBITRATION, select), timeout_ms)?;
+        Ok(Arbitration::parse(value))
+    }
+
+    pub fn power_management(
+        &self,
+        select: FeatureSelect,
+        timeout_ms: u32,
+    ) -> Result<PowerManagement, NvmeError> {
+        let (value, _) =
+            self.get_features(&GetFeatures::new(FID_POWER_MANAGEMENT, select), timeout_ms)?;
+        Ok(PowerManagement::parse(value))
+    }
+
+    pub fn temperature_threshold(
+        &self,
+        sensor: u8,
+        under: bool,
+        select: FeatureSelect,
+        timeout_ms: u32,
+    ) -> Result<TemperatureThreshold, NvmeError> {
+        let mut request = GetFeatures::new(FID_TEMPERATURE_THRESHOLD, select);
+        request.cdw11 = TemperatureThreshold::select(sensor, under);
+        let (value, _) = self.get_features(&request, timeout_ms)?;
+        Ok(TemperatureThreshold::parse(value))
+    }
+
+    pub fn volatile_write_cache(
+        &self,
+        select: FeatureSelect,
+        timeout_ms: u32,
+    ) -> Result<VolatileWriteCache, NvmeError> {
+        let (value, _) = self.get_features(
+            &GetFeatures::new(FID_VOLATILE_WRITE_CACHE, select),
+            timeout_ms,
+        )?;
+        Ok(VolatileWriteCache::parse(value))
+    }
+
+    pub fn number_of_queues(
+        &self,
+        select: FeatureSelect,
+        timeout_ms: u32,
+    ) -> Result<NumberOfQueues, NvmeError> {
+        let (value, _) =
+            self.get_features(&GetFeatures::new(FID_NUMBER_OF_QUEUES, select), timeout_ms)?;
+        Ok(NumberOfQueues::parse(value))
+    }
+
+    pub fn autonomous_power_state_transition(
+        &self,
+        select: FeatureSelect,
+        timeout_ms: u32,
+    ) -> Result<AutonomousPowerStateTransition, NvmeError> {
+        let mut request = GetFeatures::new(FID_AUTONOMOUS_POWER_STATE_TRANSITION, select);
+        request.data_len = APST_DATA_BYTES;
+        let (value, bytes) = self.get_features(&request, timeout_ms)?;
+        AutonomousPowerStateTransition::parse(value, &bytes)
+    }
+
+    pub fn host_memory_buffer(
+        &self,
+        select: FeatureSelect,
+        timeout_ms: u32,
+    ) -> Result<HostMemoryBuffer, NvmeError> {
+        let mut request = GetFeatures::new(FID_HOST_MEMORY_BUFFER, select);
+        request.data_len = HOST_MEMORY_BUFFER_DATA_BYTES;
+        let (value, bytes) = self.get_features(&request, timeout_ms)?;
+        HostMemoryBuffer::parse(value, &bytes)
+    }
+
+    pub fn timestamp(
+        &self,
+        select: FeatureSelect,
+        timeout_ms: u32,
+    ) -> Result<Timestamp, NvmeError> {
+        let mut request = GetFeatures::new(FID_TIMESTAMP, select);
+        request.data_len = TIMESTAMP_DATA_BYTES;
+        let (_, bytes) = self.get_features(&request, timeout_ms)?;
+        Timestamp::parse(&bytes)
+    }
+
+    pub fn device_self_test(&self, self_test_code: u8, timeout_ms: u32) -> Result<(), NvmeError> {
+        ioctl::device_self_test(
+            self.file.as_raw_fd(),
+            &self.path_string(),
+            NSID_ALL,
+            self_test_code,
+            timeout_ms,
+        )
+    }
+
     fn path_string(&self) -> String {
         self.path.display().to_string()
     }
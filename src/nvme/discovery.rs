@@ -8,6 +8,8 @@ use glob::Pattern;
 use crate::nvme::error::NvmeError;
 
 const SYS_CLASS_NVME: &str = "/sys/class/nvme";
+const DEFAULT_PS_MAX_LATENCY_US: &str =
+    "/sys/module/nvme_core/parameters/default_ps_max_latency_us";
 
 #[derive(Clone, Debug)]
 pub struct NvmeNamespace {
@@ -15,6 +17,14 @@ pub struct NvmeNamespace {
     pub nsid: u32,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReconciledNamespace {
+    pub name: String,
+    pub nsid: u32,
+    pub active: bool,
+    pub block_device: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct NvmeController {
     pub name: String,
@@ -148,6 +158,44 @@ fn discover_namespaces(controller_name: &str, controller_sys_path: &Path) -> Vec
     namespaces
 }
 
+pub fn reconcile_namespaces(
+    controller_name: &str,
+    sysfs: &[NvmeNamespace],
+    active_nsids: Option<&[u32]>,
+) -> Vec<ReconciledNamespace> {
+    let mut namespaces = BTreeMap::<u32, ReconciledNamespace>::new();
+    for namespace in sysfs {
+        namespaces.insert(
+            namespace.nsid,
+            ReconciledNamespace {
+                name: namespace.name.clone(),
+                nsid: namespace.nsid,
+                active: active_nsids.is_none_or(|nsids| nsids.contains(&namespace.nsid)),
+                block_device: true,
+            },
+        );
+    }
+
+    for nsid in active_nsids.unwrap_or_default() {
+        namespaces
+            .entry(*nsid)
+            .or_insert_with(|| ReconciledNamespace {
+                name: format!("{}n{}", controller_name, nsid),
+                nsid: *nsid,
+                active: true,
+                block_device: false,
+            });
+    }
+
+    namespaces.into_values().collect()
+}
+
+pub fn kernel_default_ps_max_latency_us() -> Option<u64> {
+    read_attr(PathBuf::from(DEFAULT_PS_MAX_LATENCY_US))?
+        .parse::<u64>()
+        .ok()
+}
+
 fn parse_namespace_name(controller_name: &str, namespace_name: &str) -> Option<u32> {
     let prefix = format!("{}n", controller_name);
     let suffix = namespace_name.strip_prefix(&prefix)?;
@@ -166,7 +214,7 @@ fn parse_namespace_name(controller_name: &str, namespace_name: &str) -> Option<u
     digits.parse::<u32>().ok()
 }
 
-fn is_controller_name(value: &str) -> bool {
+pub(crate) fn is_controller_name(value: &str) -> bool {
     let Some(suffix) = value.strip_prefix("nvme") else {
         return false;
     };
@@ -187,6 +235,8 @@ fn read_attr(path: PathBuf) -> Option<String> {
 mod tests {
     use crate::nvme::discovery::is_controller_name;
     use crate::nvme::discovery::parse_namespace_name;
+    use crate::nvme::discovery::reconcile_namespaces;
+    use crate::nvme::discovery::NvmeNamespace;
 
     #[test]
     fn parses_namespace_ids() {
@@ -201,6 +251,46 @@ mod tests {
         assert_eq!(parse_namespace_name("nvme0", "nvme0np1"), None);
     }
 
+    #[test]
+    fn reconciles_sysfs_against_active_namespace_list() {
+        let sysfs = vec![
+            NvmeNamespace {
+                name: "nvme0n1".to_string(),
+                nsid: 1,
+            },
+            NvmeNamespace {
+                name: "nvme0n3".to_string(),
+                nsid: 3,
+            },
+        ];
+
+        let reconciled = reconcile_namespaces("nvme0", &sysfs, Some(&[1, 2]));
+        let summary: Vec<(&str, u32, bool, bool)> = reconciled
+            .iter()
+            .map(|value| {
+                (
+                    value.name.as_str(),
+                    value.nsid,
+                    value.active,
+                    value.block_device,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("nvme0n1", 1, true, true),
+                ("nvme0n2", 2, true, false),
+                ("nvme0n3", 3, false, true),
+            ]
+        );
+
+        let fallback = reconcile_namespaces("nvme0", &sysfs, None);
+        assert!(fallback
+            .iter()
+            .all(|value| value.active && value.block_device));
+    }
+
     #[test]
     fn matches_controller_names_only() {
         assert!(is_controller_name("nvme0"));
@@ -1,6 +1,8 @@
 use std::fmt;
 use std::path::Path;
 
+use crate::nvme::status::NvmeStatus;
+
 #[derive(Debug)]
 pub enum NvmeError {
     Io {
@@ -14,6 +16,12 @@ pub enum NvmeError {
     PermissionDenied {
         device: String,
     },
+    CommandStatus {
+        device: String,
+        opcode: u8,
+        lid: Option<u8>,
+        status: NvmeStatus,
+    },
     UnexpectedSize {
         expected: usize,
         actual: usize,
@@ -54,6 +62,23 @@ impl fmt::Display for NvmeError {
                     device
                 )
             }
+            NvmeError::CommandStatus {
+                device,
+                opcode,
+                lid,
+                status,
+            } => match lid {
+                Some(lid) => write!(
+                    f,
+                    "command 0x{:02x} (log page 0x{:02x}) on {} failed with status {}",
+                    opcode, lid, device, status
+                ),
+                None => write!(
+                    f,
+                    "command 0x{:02x} on {} failed with status {}",
+                    opcode, device, status
+                ),
+            },
             NvmeError::UnexpectedSize { expected, actual } => {
                 write!(
                     f,
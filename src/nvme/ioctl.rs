@@ -1,12 +1,25 @@
 use std::os::fd::RawFd;
 
 use crate::nvme::error::NvmeError;
+use crate::nvme::status::NvmeStatus;
 use crate::nvme::types::IDENTIFY_BYTES;
 
 const NVME_IOCTL_ADMIN_CMD: libc::c_ulong = 0xC048_4E41;
+const NVME_IOCTL_IO_CMD: libc::c_ulong = 0xC048_4E43;
 const OPCODE_IDENTIFY: u8 = 0x06;
 const OPCODE_GET_LOG_PAGE: u8 = 0x02;
+const OPCODE_GET_FEATURES: u8 = 0x0A;
+const OPCODE_DEVICE_SELF_TEST: u8 = 0x14;
+const OPCODE_ZONE_MANAGEMENT_RECEIVE: u8 = 0x7A;
 const NSID_ALL: u32 = 0xFFFF_FFFF;
+pub const DEFAULT_LOG_PAGE_TRANSFER_BYTES: usize = 4096;
+pub const MAX_LOG_PAGE_TRANSFER_BYTES: usize = 1024 * 1024;
+const CNS_NAMESPACE: u32 = 0x00;
+const CNS_CONTROLLER: u32 = 0x01;
+const CNS_ACTIVE_NAMESPACE_LIST: u32 = 0x02;
+const CNS_NAMESPACE_DESCRIPTORS: u32 = 0x03;
+const CNS_COMMAND_SET_NAMESPACE: u32 = 0x05;
+const CNS_ENDURANCE_GROUP_LIST: u32 = 0x19;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
@@ -31,6 +44,71 @@ pub struct NvmePassthruCmd {
     pub result: u32,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeatureSelect {
+    Current,
+    Default,
+    Saved,
+    SupportedCapabilities,
+}
+
+impl FeatureSelect {
+    fn raw(self) -> u32 {
+        match self {
+            FeatureSelect::Current => 0b000,
+            FeatureSelect::Default => 0b001,
+            FeatureSelect::Saved => 0b010,
+            FeatureSelect::SupportedCapabilities => 0b011,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GetFeatures {
+    pub fid: u8,
+    pub nsid: u32,
+    pub select: FeatureSelect,
+    pub cdw11: u32,
+    pub data_len: usize,
+}
+
+impl GetFeatures {
+    pub fn new(fid: u8, select: FeatureSelect) -> Self {
+        Self {
+            fid,
+            nsid: 0,
+            select,
+            cdw11: 0,
+            data_len: 0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GetLogPage {
+    pub lid: u8,
+    pub nsid: u32,
+    pub lsp: u8,
+    pub lsi: u16,
+    pub rae: bool,
+    pub offset: u64,
+    pub data_len: usize,
+}
+
+impl GetLogPage {
+    pub fn new(lid: u8, data_len: usize) -> Self {
+        Self {
+            lid,
+            nsid: NSID_ALL,
+            lsp: 0,
+            lsi: 0,
+            rae: true,
+            offset: 0,
+            data_len,
+        }
+    }
+}
+
 impl NvmePassthruCmd {
     fn empty() -> Self {
         Self {
@@ -61,20 +139,7 @@ pub fn identify_controller(
     device_name: &str,
     timeout_ms: u32,
 ) -> Result<[u8; IDENTIFY_BYTES], NvmeError> {
-    let mut buffer = [0_u8; IDENTIFY_BYTES];
-    let data_len = u32::try_from(buffer.len()).map_err(|_| {
-        NvmeError::InvalidData("identify controller buffer length exceeds u32".to_string())
-    })?;
-    let mut cmd = NvmePassthruCmd::empty();
-    cmd.opcode = OPCODE_IDENTIFY;
-    cmd.nsid = 0;
-    cmd.addr = buffer.as_mut_ptr() as u64;
-    cmd.data_len = data_len;
-    cmd.cdw10 = 0x01;
-    cmd.timeout_ms = timeout_ms;
-
-    admin_cmd(fd, device_name, &mut cmd)?;
-    Ok(buffer)
+    identify(fd, device_name, 0, CNS_CONTROLLER, 0, timeout_ms)
 }
 
 pub fn identify_namespace(
@@ -82,17 +147,93 @@ pub fn identify_namespace(
     device_name: &str,
     nsid: u32,
     timeout_ms: u32,
+) -> Result<[u8; IDENTIFY_BYTES], NvmeError> {
+    identify(fd, device_name, nsid, CNS_NAMESPACE, 0, timeout_ms)
+}
+
+pub fn identify_active_namespace_list(
+    fd: RawFd,
+    device_name: &str,
+    start_nsid: u32,
+    timeout_ms: u32,
+) -> Result<[u8; IDENTIFY_BYTES], NvmeError> {
+    identify(
+        fd,
+        device_name,
+        start_nsid,
+        CNS_ACTIVE_NAMESPACE_LIST,
+        0,
+        timeout_ms,
+    )
+}
+
+pub fn identify_namespace_descriptors(
+    fd: RawFd,
+    device_name: &str,
+    nsid: u32,
+    timeout_ms: u32,
+) -> Result<[u8; IDENTIFY_BYTES], NvmeError> {
+    identify(
+        fd,
+        device_name,
+        nsid,
+        CNS_NAMESPACE_DESCRIPTORS,
+        0,
+        timeout_ms,
+    )
+}
+
+pub fn identify_command_set_namespace(
+    fd: RawFd,
+    device_name: &str,
+    nsid: u32,
+    csi: u8,
+    timeout_ms: u32,
+) -> Result<[u8; IDENTIFY_BYTES], NvmeError> {
+    identify(
+        fd,
+        device_name,
+        nsid,
+        CNS_COMMAND_SET_NAMESPACE,
+        u32::from(csi) << 24,
+        timeout_ms,
+    )
+}
+
+pub fn identify_endurance_group_list(
+    fd: RawFd,
+    device_name: &str,
+    start_id: u16,
+    timeout_ms: u32,
+) -> Result<[u8; IDENTIFY_BYTES], NvmeError> {
+    identify(
+        fd,
+        device_name,
+        0,
+        CNS_ENDURANCE_GROUP_LIST,
+        u32::from(start_id),
+        timeout_ms,
+    )
+}
+
+fn identify(
+    fd: RawFd,
+    device_name: &str,
+    nsid: u32,
+    cdw10: u32,
+    cdw11: u32,
+    timeout_ms: u32,
 ) -> Result<[u8; IDENTIFY_BYTES], NvmeError> {
     let mut buffer = [0_u8; IDENTIFY_BYTES];
-    let data_len = u32::try_from(buffer.len()).map_err(|_| {
-        NvmeError::InvalidData("identify namespace buffer length exceeds u32".to_string())
-    })?;
+    let data_len = u32::try_from(buffer.len())
+        .map_err(|_| NvmeError::InvalidData("identify buffer length exceeds u32".to_string()))?;
     let mut cmd = NvmePassthruCmd::empty();
     cmd.opcode = OPCODE_IDENTIFY;
     cmd.nsid = nsid;
     cmd.addr = buffer.as_mut_ptr() as u64;
     cmd.data_len = data_len;
-    cmd.cdw10 = 0x00;
+    cmd.cdw10 = cdw10;
+    cmd.cdw11 = cdw11;
     cmd.timeout_ms = timeout_ms;
 
     admin_cmd(fd, device_name, &mut cmd)?;
@@ -102,35 +243,109 @@ pub fn identify_namespace(
 pub fn get_log_page(
     fd: RawFd,
     device_name: &str,
-    nsid: u32,
-    lid: u8,
-    data_len: usize,
+    request: &GetLogPage,
+    timeout_ms: u32,
+) -> Result<Vec<u8>, NvmeError> {
+    let mut buffer = vec![0_u8; request.data_len];
+    let mut cmd = build_get_log_page(request, &mut buffer, timeout_ms)?;
+
+    admin_cmd(fd, device_name, &mut cmd)?;
+    Ok(buffer)
+}
+
+pub fn get_log_page_chunked(
+    fd: RawFd,
+    device_name: &str,
+    request: &GetLogPage,
+    max_transfer_bytes: usize,
+    extended_log_page: bool,
     timeout_ms: u32,
 ) -> Result<Vec<u8>, NvmeError> {
-    if data_len == 0 || !data_len.is_multiple_of(4) {
+    let mut buffer = Vec::with_capacity(request.data_len);
+    for chunk in log_page_chunks(request, max_transfer_bytes, extended_log_page)? {
+        buffer.extend_from_slice(&get_log_page(fd, device_name, &chunk, timeout_ms)?);
+    }
+    Ok(buffer)
+}
+
+fn log_page_chunks(
+    request: &GetLogPage,
+    max_transfer_bytes: usize,
+    extended_log_page: bool,
+) -> Result<Vec<GetLogPage>, NvmeError> {
+    if request.data_len == 0 || !request.data_len.is_multiple_of(4) {
+        return Err(NvmeError::InvalidData(format!(
+            "log page length {} must be non-zero and divisible by 4",
+            request.data_len
+        )));
+    }
+
+    let chunk_len = (max_transfer_bytes & !3).clamp(4, MAX_LOG_PAGE_TRANSFER_BYTES);
+    let mut chunks = Vec::with_capacity(request.data_len.div_ceil(chunk_len));
+    let mut read = 0;
+    while read < request.data_len {
+        let data_len = chunk_len.min(request.data_len - read);
+        let offset = request.offset.checked_add(read as u64).ok_or_else(|| {
+            NvmeError::InvalidData(format!(
+                "log page 0x{:02x} offset {} overflows at {} bytes",
+                request.lid, request.offset, read
+            ))
+        })?;
+        if !extended_log_page && (offset != 0 || data_len / 4 - 1 > 0xFFFF) {
+            return Err(NvmeError::InvalidData(format!(
+                "log page 0x{:02x} read of {} bytes at offset {} needs extended Get Log Page \
+                 support, which the controller does not advertise (LPA bit 2)",
+                request.lid, data_len, offset
+            )));
+        }
+        chunks.push(GetLogPage {
+            offset,
+            data_len,
+            ..*request
+        });
+        read += data_len;
+    }
+    Ok(chunks)
+}
+
+fn build_get_log_page(
+    request: &GetLogPage,
+    buffer: &mut [u8],
+    timeout_ms: u32,
+) -> Result<NvmePassthruCmd, NvmeError> {
+    if buffer.is_empty() || !buffer.len().is_multiple_of(4) {
         return Err(NvmeError::InvalidData(format!(
             "log page length {} must be non-zero and divisible by 4",
-            data_len
+            buffer.len()
+        )));
+    }
+    if !request.offset.is_multiple_of(4) {
+        return Err(NvmeError::InvalidData(format!(
+            "log page offset {} must be divisible by 4",
+            request.offset
         )));
     }
 
-    let numd_words = (data_len / 4).saturating_sub(1);
+    let numd_words = (buffer.len() / 4).saturating_sub(1);
     let numd_words = u32::try_from(numd_words)
         .map_err(|_| NvmeError::InvalidData("log page length is too large".to_string()))?;
-    let data_len_u32 = u32::try_from(data_len)
+    let data_len = u32::try_from(buffer.len())
         .map_err(|_| NvmeError::InvalidData("log page length is too large".to_string()))?;
 
-    let mut buffer = vec![0_u8; data_len];
     let mut cmd = NvmePassthruCmd::empty();
     cmd.opcode = OPCODE_GET_LOG_PAGE;
-    cmd.nsid = nsid;
+    cmd.nsid = request.nsid;
     cmd.addr = buffer.as_mut_ptr() as u64;
-    cmd.data_len = data_len_u32;
-    cmd.cdw10 = (numd_words << 16) | u32::from(lid);
+    cmd.data_len = data_len;
+    cmd.cdw10 = ((numd_words & 0xFFFF) << 16)
+        | (u32::from(request.rae) << 15)
+        | (u32::from(request.lsp & 0x7F) << 8)
+        | u32::from(request.lid);
+    cmd.cdw11 = (u32::from(request.lsi) << 16) | (numd_words >> 16);
+    cmd.cdw12 = request.offset as u32;
+    cmd.cdw13 = (request.offset >> 32) as u32;
     cmd.timeout_ms = timeout_ms;
-
-    admin_cmd(fd, device_name, &mut cmd)?;
-    Ok(buffer)
+    Ok(cmd)
 }
 
 pub fn get_controller_log_page(
@@ -140,11 +355,117 @@ pub fn get_controller_log_page(
     data_len: usize,
     timeout_ms: u32,
 ) -> Result<Vec<u8>, NvmeError> {
-    get_log_page(fd, device_name, NSID_ALL, lid, data_len, timeout_ms)
+    get_log_page(fd, device_name, &GetLogPage::new(lid, data_len), timeout_ms)
+}
+
+pub fn device_self_test(
+    fd: RawFd,
+    device_name: &str,
+    nsid: u32,
+    self_test_code: u8,
+    timeout_ms: u32,
+) -> Result<(), NvmeError> {
+    let mut cmd = NvmePassthruCmd::empty();
+    cmd.opcode = OPCODE_DEVICE_SELF_TEST;
+    cmd.nsid = nsid;
+    cmd.cdw10 = u32::from(self_test_code & 0x0F);
+    cmd.timeout_ms = timeout_ms;
+
+    admin_cmd(fd, device_name, &mut cmd)?;
+    Ok(())
+}
+
+pub fn get_features(
+    fd: RawFd,
+    device_name: &str,
+    request: &GetFeatures,
+    timeout_ms: u32,
+) -> Result<(u32, Vec<u8>), NvmeError> {
+    let mut buffer = vec![0_u8; request.data_len];
+    let mut cmd = build_get_features(request, &mut buffer, timeout_ms)?;
+
+    let value = admin_cmd(fd, device_name, &mut cmd)?;
+    Ok((value, buffer))
+}
+
+fn build_get_features(
+    request: &GetFeatures,
+    buffer: &mut [u8],
+    timeout_ms: u32,
+) -> Result<NvmePassthruCmd, NvmeError> {
+    let data_len = u32::try_from(buffer.len())
+        .map_err(|_| NvmeError::InvalidData("feature buffer length is too large".to_string()))?;
+
+    let mut cmd = NvmePassthruCmd::empty();
+    cmd.opcode = OPCODE_GET_FEATURES;
+    cmd.nsid = request.nsid;
+    if !buffer.is_empty() {
+        cmd.addr = buffer.as_mut_ptr() as u64;
+        cmd.data_len = data_len;
+    }
+    cmd.cdw10 = (request.select.raw() << 8) | u32::from(request.fid);
+    cmd.cdw11 = request.cdw11;
+    cmd.timeout_ms = timeout_ms;
+    Ok(cmd)
+}
+
+pub fn zone_management_receive(
+    fd: RawFd,
+    device_name: &str,
+    nsid: u32,
+    state_filter: u8,
+    data_len: usize,
+    timeout_ms: u32,
+) -> Result<Vec<u8>, NvmeError> {
+    let mut buffer = vec![0_u8; data_len];
+    let mut cmd = build_zone_management_receive(nsid, state_filter, &mut buffer, timeout_ms)?;
+
+    io_cmd(fd, device_name, &mut cmd)?;
+    Ok(buffer)
+}
+
+fn build_zone_management_receive(
+    nsid: u32,
+    state_filter: u8,
+    buffer: &mut [u8],
+    timeout_ms: u32,
+) -> Result<NvmePassthruCmd, NvmeError> {
+    if buffer.is_empty() || !buffer.len().is_multiple_of(4) {
+        return Err(NvmeError::InvalidData(format!(
+            "zone report length {} must be non-zero and divisible by 4",
+            buffer.len()
+        )));
+    }
+
+    let data_len = u32::try_from(buffer.len())
+        .map_err(|_| NvmeError::InvalidData("zone report length is too large".to_string()))?;
+
+    let mut cmd = NvmePassthruCmd::empty();
+    cmd.opcode = OPCODE_ZONE_MANAGEMENT_RECEIVE;
+    cmd.nsid = nsid;
+    cmd.addr = buffer.as_mut_ptr() as u64;
+    cmd.data_len = data_len;
+    cmd.cdw12 = (data_len / 4) - 1;
+    cmd.cdw13 = u32::from(state_filter) << 8;
+    cmd.timeout_ms = timeout_ms;
+    Ok(cmd)
 }
 
-fn admin_cmd(fd: RawFd, device_name: &str, cmd: &mut NvmePassthruCmd) -> Result<(), NvmeError> {
-    let ret = unsafe { libc::ioctl(fd, NVME_IOCTL_ADMIN_CMD as _, cmd as *mut NvmePassthruCmd) };
+fn admin_cmd(fd: RawFd, device_name: &str, cmd: &mut NvmePassthruCmd) -> Result<u32, NvmeError> {
+    passthru(fd, device_name, NVME_IOCTL_ADMIN_CMD, cmd)
+}
+
+fn io_cmd(fd: RawFd, device_name: &str, cmd: &mut NvmePassthruCmd) -> Result<u32, NvmeError> {
+    passthru(fd, device_name, NVME_IOCTL_IO_CMD, cmd)
+}
+
+fn passthru(
+    fd: RawFd,
+    device_name: &str,
+    request: libc::c_ulong,
+    cmd: &mut NvmePassthruCmd,
+) -> Result<u32, NvmeError> {
+    let ret = unsafe { libc::ioctl(fd, request as _, cmd as *mut NvmePassthruCmd) };
 
     if ret < 0 {
         let source = std::io::Error::last_os_error();
@@ -159,15 +480,183 @@ fn admin_cmd(fd: RawFd, device_name: &str, cmd: &mut NvmePassthruCmd) -> Result<
         });
     }
 
-    Ok(())
+    if ret > 0 {
+        return Err(NvmeError::CommandStatus {
+            device: device_name.to_string(),
+            opcode: cmd.opcode,
+            lid: log_page_id(cmd),
+            status: NvmeStatus::from_raw((ret & 0xFFFF) as u16),
+        });
+    }
+
+    Ok(cmd.result)
+}
+
+fn log_page_id(cmd: &NvmePassthruCmd) -> Option<u8> {
+    if cmd.opcode == OPCODE_GET_LOG_PAGE {
+        Some((cmd.cdw10 & 0xFF) as u8)
+    } else {
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::nvme::ioctl::build_get_features;
+    use crate::nvme::ioctl::build_get_log_page;
+    use crate::nvme::ioctl::build_zone_management_receive;
+    use crate::nvme::ioctl::log_page_chunks;
+    use crate::nvme::ioctl::FeatureSelect;
+    use crate::nvme::ioctl::GetFeatures;
+    use crate::nvme::ioctl::GetLogPage;
     use crate::nvme::ioctl::NvmePassthruCmd;
+    use crate::nvme::ioctl::MAX_LOG_PAGE_TRANSFER_BYTES;
 
     #[test]
     fn passthrough_layout_matches_kernel() {
         assert_eq!(std::mem::size_of::<NvmePassthruCmd>(), 72);
     }
+
+    #[test]
+    fn get_log_page_encodes_length_and_log_specific_identifier() {
+        let mut request = GetLogPage::new(0x09, 512);
+        request.lsi = 3;
+        let mut buffer = [0_u8; 512];
+        let cmd = build_get_log_page(&request, &mut buffer, 1000).expect("command should build");
+        assert_eq!(cmd.opcode, 0x02);
+        assert_eq!(cmd.nsid, 0xFFFF_FFFF);
+        assert_eq!(cmd.cdw10, (127 << 16) | (1 << 15) | 0x09);
+        assert_eq!(cmd.cdw11, 3 << 16);
+        assert_eq!(cmd.data_len, 512);
+        assert_eq!((cmd.cdw12, cmd.cdw13), (0, 0));
+        assert!(build_get_log_page(&request, &mut [0_u8; 6], 1000).is_err());
+    }
+
+    #[test]
+    fn get_log_page_encodes_specific_field_and_offset() {
+        let mut request = GetLogPage::new(0x0D, 4096);
+        request.lsp = 0x01;
+        request.offset = 0x1_0000_0200;
+        let mut buffer = [0_u8; 4096];
+        let cmd = build_get_log_page(&request, &mut buffer, 1000).expect("command should build");
+        assert_eq!(cmd.cdw10, (1023 << 16) | (1 << 15) | (0x01 << 8) | 0x0D);
+        assert_eq!(cmd.cdw12, 0x200);
+        assert_eq!(cmd.cdw13, 0x1);
+        request.offset = 2;
+        assert!(build_get_log_page(&request, &mut buffer, 1000).is_err());
+    }
+
+    #[test]
+    fn get_log_page_retains_asynchronous_events_by_default() {
+        let mut buffer = [0_u8; 512];
+        for lid in [0x01, 0x02, 0x03, 0x06, 0x0D, 0xC0] {
+            let request = GetLogPage::new(lid, 512);
+            let cmd =
+                build_get_log_page(&request, &mut buffer, 1000).expect("command should build");
+            assert_eq!(cmd.cdw10 & (1 << 15), 1 << 15, "lid 0x{:02x}", lid);
+        }
+
+        let mut request = GetLogPage::new(0x02, 512);
+        request.lsp = 0x7F;
+        let cmd = build_get_log_page(&request, &mut buffer, 1000).expect("command should build");
+        assert_eq!((cmd.cdw10 >> 8) & 0xFF, 0xFF);
+        request.rae = false;
+        let cmd = build_get_log_page(&request, &mut buffer, 1000).expect("command should build");
+        assert_eq!(cmd.cdw10 & (1 << 15), 0);
+        assert_eq!((cmd.cdw10 >> 8) & 0x7F, 0x7F);
+    }
+
+    #[test]
+    fn log_page_chunks_keep_retain_asynchronous_event() {
+        let request = GetLogPage::new(0x07, 8192);
+        let chunks = log_page_chunks(&request, 4096, true).expect("chunks should plan");
+        let mut buffer = [0_u8; 4096];
+        for chunk in &chunks {
+            let cmd = build_get_log_page(chunk, &mut buffer, 1000).expect("command should build");
+            assert_ne!(cmd.cdw10 & (1 << 15), 0);
+        }
+    }
+
+    #[test]
+    fn get_log_page_splits_number_of_dwords_across_cdw10_and_cdw11() {
+        let mut request = GetLogPage::new(0x07, 512 * 1024);
+        request.lsi = 2;
+        let mut buffer = vec![0_u8; 512 * 1024];
+        let cmd = build_get_log_page(&request, &mut buffer, 1000).expect("command should build");
+        let numd = 512 * 1024 / 4 - 1;
+        assert_eq!(cmd.cdw10, ((numd & 0xFFFF) << 16) | (1 << 15) | 0x07);
+        assert_eq!(cmd.cdw11, (2 << 16) | (numd >> 16));
+    }
+
+    #[test]
+    fn log_page_chunks_respect_transfer_size_and_offset() {
+        let mut request = GetLogPage::new(0x0D, 10_000);
+        request.offset = 512;
+        let chunks = log_page_chunks(&request, 4096, true).expect("chunks should plan");
+        let layout: Vec<(u64, usize)> = chunks
+            .iter()
+            .map(|chunk| (chunk.offset, chunk.data_len))
+            .collect();
+        assert_eq!(layout, vec![(512, 4096), (4608, 4096), (8704, 1808)]);
+        assert!(chunks.iter().all(|chunk| chunk.lid == 0x0D));
+
+        let chunks = log_page_chunks(&request, 4098, true).expect("chunks should plan");
+        assert_eq!(chunks[0].data_len, 4096);
+        assert!(log_page_chunks(&GetLogPage::new(0x0D, 6), 4096, true).is_err());
+
+        request.offset = u64::MAX - 3;
+        assert!(log_page_chunks(&request, 4096, true).is_err());
+    }
+
+    #[test]
+    fn log_page_chunks_require_extended_support_for_offsets_and_numdu() {
+        let chunks = log_page_chunks(&GetLogPage::new(0x0D, 4096), 4096, false)
+            .expect("single chunk should plan");
+        assert_eq!(chunks.len(), 1);
+
+        assert!(log_page_chunks(&GetLogPage::new(0x0D, 8192), 4096, false).is_err());
+
+        let mut request = GetLogPage::new(0x0D, 512);
+        request.offset = 512;
+        assert!(log_page_chunks(&request, 4096, false).is_err());
+
+        let request = GetLogPage::new(0x07, 256 * 1024 + 4);
+        assert!(log_page_chunks(&request, MAX_LOG_PAGE_TRANSFER_BYTES, false).is_err());
+        assert!(log_page_chunks(&request, MAX_LOG_PAGE_TRANSFER_BYTES, true).is_ok());
+    }
+
+    #[test]
+    fn get_features_encodes_select_and_fid() {
+        let mut request = GetFeatures::new(0x0C, FeatureSelect::Saved);
+        request.cdw11 = 0x1234;
+        let mut buffer = [0_u8; 256];
+        let cmd = build_get_features(&request, &mut buffer, 1000).expect("command should build");
+        assert_eq!(cmd.opcode, 0x0A);
+        assert_eq!(cmd.cdw10, (0b010 << 8) | 0x0C);
+        assert_eq!(cmd.cdw11, 0x1234);
+        assert_eq!(cmd.data_len, 256);
+        assert_ne!(cmd.addr, 0);
+    }
+
+    #[test]
+    fn zone_management_receive_encodes_filter_and_length() {
+        let mut buffer = [0_u8; 4096];
+        let cmd = build_zone_management_receive(1, 0x05, &mut buffer, 1000)
+            .expect("command should build");
+        assert_eq!(cmd.opcode, 0x7A);
+        assert_eq!(cmd.nsid, 1);
+        assert_eq!(cmd.cdw10, 0);
+        assert_eq!(cmd.cdw12, 1023);
+        assert_eq!(cmd.cdw13, 0x05 << 8);
+        assert!(build_zone_management_receive(1, 0, &mut [0_u8; 6], 1000).is_err());
+    }
+
+    #[test]
+    fn get_features_without_data_has_no_buffer() {
+        let request = GetFeatures::new(0x07, FeatureSelect::SupportedCapabilities);
+        let cmd = build_get_features(&request, &mut [], 1000).expect("command should build");
+        assert_eq!(cmd.cdw10, (0b011 << 8) | 0x07);
+        assert_eq!(cmd.data_len, 0);
+        assert_eq!(cmd.addr, 0);
+    }
 }
@@ -2,4 +2,6 @@ pub mod device;
 pub mod discovery;
 pub mod error;
 pub mod ioctl;
+pub mod status;
 pub mod types;
+pub mod vendor;
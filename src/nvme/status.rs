@@ -0,0 +1,265 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatusCodeType {
+    Generic,
+    CommandSpecific,
+    MediaAndDataIntegrity,
+    PathRelated,
+    VendorSpecific,
+    Reserved(u8),
+}
+
+impl StatusCodeType {
+    pub fn from_raw(value: u8) -> Self {
+        match value & 0x07 {
+            0x0 => Self::Generic,
+            0x1 => Self::CommandSpecific,
+            0x2 => Self::MediaAndDataIntegrity,
+            0x3 => Self::PathRelated,
+            0x7 => Self::VendorSpecific,
+            other => Self::Reserved(other),
+        }
+    }
+
+    pub fn raw(&self) -> u8 {
+        match self {
+            Self::Generic => 0x0,
+            Self::CommandSpecific => 0x1,
+            Self::MediaAndDataIntegrity => 0x2,
+            Self::PathRelated => 0x3,
+            Self::VendorSpecific => 0x7,
+            Self::Reserved(value) => *value,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Generic => "generic",
+            Self::CommandSpecific => "command_specific",
+            Self::MediaAndDataIntegrity => "media_and_data_integrity",
+            Self::PathRelated => "path_related",
+            Self::VendorSpecific => "vendor_specific",
+            Self::Reserved(_) => "reserved",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NvmeStatus {
+    pub raw: u16,
+    pub status_code_type: StatusCodeType,
+    pub status_code: u8,
+    pub command_retry_delay: u8,
+    pub more: bool,
+    pub do_not_retry: bool,
+}
+
+impl NvmeStatus {
+    pub fn from_raw(raw: u16) -> Self {
+        Self {
+            raw,
+            status_code_type: StatusCodeType::from_raw(((raw >> 8) & 0x07) as u8),
+            status_code: (raw & 0xFF) as u8,
+            command_retry_delay: ((raw >> 11) & 0x03) as u8,
+            more: (raw & (1 << 13)) != 0,
+            do_not_retry: (raw & (1 << 14)) != 0,
+        }
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        match self.status_code_type {
+            StatusCodeType::Generic => generic_status_name(self.status_code),
+            StatusCodeType::CommandSpecific => command_specific_status_name(self.status_code),
+            StatusCodeType::MediaAndDataIntegrity => media_status_name(self.status_code),
+            StatusCodeType::PathRelated => path_status_name(self.status_code),
+            StatusCodeType::VendorSpecific | StatusCodeType::Reserved(_) => None,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self.name() {
+            Some(name) => name.to_string(),
+            None => format!(
+                "{}_0x{:02x}",
+                self.status_code_type.name(),
+                self.status_code
+            ),
+        }
+    }
+}
+
+impl fmt::Display for NvmeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (sct 0x{:x} {}, sc 0x{:02x}",
+            self.label(),
+            self.status_code_type.raw(),
+            self.status_code_type.name(),
+            self.status_code
+        )?;
+        if self.more {
+            write!(f, ", more")?;
+        }
+        if self.do_not_retry {
+            write!(f, ", dnr")?;
+        }
+        write!(f, ")")
+    }
+}
+
+fn generic_status_name(code: u8) -> Option<&'static str> {
+    let name = match code {
+        0x00 => "successful_completion",
+        0x01 => "invalid_command_opcode",
+        0x02 => "invalid_field_in_command",
+        0x03 => "command_id_conflict",
+        0x04 => "data_transfer_error",
+        0x05 => "aborted_power_loss",
+        0x06 => "internal_error",
+        0x07 => "aborted_by_request",
+        0x08 => "aborted_sq_deletion",
+        0x09 => "aborted_failed_fused",
+        0x0A => "aborted_missing_fused",
+        0x0B => "invalid_namespace_or_format",
+        0x0C => "command_sequence_error",
+        0x0D => "invalid_sgl_segment_descriptor",
+        0x0E => "invalid_number_of_sgl_descriptors",
+        0x0F => "data_sgl_length_invalid",
+        0x10 => "metadata_sgl_length_invalid",
+        0x11 => "sgl_descriptor_type_invalid",
+        0x12 => "invalid_use_of_controller_memory_buffer",
+        0x13 => "prp_offset_invalid",
+        0x14 => "atomic_write_unit_exceeded",
+        0x15 => "operation_denied",
+        0x16 => "sgl_offset_invalid",
+        0x18 => "host_identifier_inconsistent_format",
+        0x19 => "keep_alive_timer_expired",
+        0x1A => "keep_alive_timeout_invalid",
+        0x1B => "aborted_preempt_and_abort",
+        0x1C => "sanitize_failed",
+        0x1D => "sanitize_in_progress",
+        0x1E => "sgl_data_block_granularity_invalid",
+        0x1F => "command_not_supported_for_queue_in_cmb",
+        0x20 => "namespace_is_write_protected",
+        0x21 => "command_interrupted",
+        0x22 => "transient_transport_error",
+        0x80 => "lba_out_of_range",
+        0x81 => "capacity_exceeded",
+        0x82 => "namespace_not_ready",
+        0x83 => "reservation_conflict",
+        0x84 => "format_in_progress",
+        _ => return None,
+    };
+    Some(name)
+}
+
+fn command_specific_status_name(code: u8) -> Option<&'static str> {
+    let name = match code {
+        0x00 => "completion_queue_invalid",
+        0x01 => "invalid_queue_identifier",
+        0x02 => "invalid_queue_size",
+        0x03 => "abort_command_limit_exceeded",
+        0x05 => "asynchronous_event_request_limit_exceeded",
+        0x06 => "invalid_firmware_slot",
+        0x07 => "invalid_firmware_image",
+        0x08 => "invalid_interrupt_vector",
+        0x09 => "invalid_log_page",
+        0x0A => "invalid_format",
+        0x0B => "firmware_activation_requires_conventional_reset",
+        0x0C => "invalid_queue_deletion",
+        0x0D => "feature_identifier_not_saveable",
+        0x0E => "feature_not_changeable",
+        0x0F => "feature_not_namespace_specific",
+        0x10 => "firmware_activation_requires_nvm_subsystem_reset",
+        0x11 => "firmware_activation_requires_controller_level_reset",
+        0x12 => "firmware_activation_requires_maximum_time_violation",
+        0x13 => "firmware_activation_prohibited",
+        0x14 => "overlapping_range",
+        0x15 => "namespace_insufficient_capacity",
+        0x16 => "namespace_identifier_unavailable",
+        0x18 => "namespace_already_attached",
+        0x19 => "namespace_is_private",
+        0x1A => "namespace_not_attached",
+        0x1B => "thin_provisioning_not_supported",
+        0x1C => "controller_list_invalid",
+        0x1D => "device_self_test_in_progress",
+        0x1E => "boot_partition_write_prohibited",
+        0x1F => "invalid_controller_identifier",
+        0x20 => "invalid_secondary_controller_state",
+        0x21 => "invalid_number_of_controller_resources",
+        0x22 => "invalid_resource_identifier",
+        0x23 => "sanitize_prohibited_while_pmr_enabled",
+        0x24 => "ana_group_identifier_invalid",
+        0x25 => "ana_attach_failed",
+        0x80 => "conflicting_attributes",
+        0x81 => "invalid_protection_information",
+        0x82 => "attempted_write_to_read_only_range",
+        _ => return None,
+    };
+    Some(name)
+}
+
+fn media_status_name(code: u8) -> Option<&'static str> {
+    let name = match code {
+        0x80 => "write_fault",
+        0x81 => "unrecovered_read_error",
+        0x82 => "end_to_end_guard_check_error",
+        0x83 => "end_to_end_application_tag_check_error",
+        0x84 => "end_to_end_reference_tag_check_error",
+        0x85 => "compare_failure",
+        0x86 => "access_denied",
+        0x87 => "deallocated_or_unwritten_logical_block",
+        _ => return None,
+    };
+    Some(name)
+}
+
+fn path_status_name(code: u8) -> Option<&'static str> {
+    let name = match code {
+        0x00 => "internal_path_error",
+        0x01 => "asymmetric_access_persistent_loss",
+        0x02 => "asymmetric_access_inaccessible",
+        0x03 => "asymmetric_access_transition",
+        0x60 => "controller_pathing_error",
+        0x70 => "host_pathing_error",
+        0x71 => "command_aborted_by_host",
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nvme::status::NvmeStatus;
+    use crate::nvme::status::StatusCodeType;
+
+    #[test]
+    fn decodes_invalid_log_page_with_dnr() {
+        let status = NvmeStatus::from_raw(0x4109);
+        assert_eq!(status.status_code_type, StatusCodeType::CommandSpecific);
+        assert_eq!(status.status_code, 0x09);
+        assert!(status.do_not_retry);
+        assert!(!status.more);
+        assert_eq!(status.label(), "invalid_log_page");
+    }
+
+    #[test]
+    fn decodes_generic_invalid_field() {
+        let status = NvmeStatus::from_raw(0x0002);
+        assert_eq!(status.status_code_type, StatusCodeType::Generic);
+        assert_eq!(status.label(), "invalid_field_in_command");
+        assert_eq!(
+            status.to_string(),
+            "invalid_field_in_command (sct 0x0 generic, sc 0x02)"
+        );
+    }
+
+    #[test]
+    fn unknown_codes_fall_back_to_numeric_label() {
+        let status = NvmeStatus::from_raw(0x07C1);
+        assert_eq!(status.status_code_type, StatusCodeType::VendorSpecific);
+        assert_eq!(status.label(), "vendor_specific_0xc1");
+    }
+}
@@ -1,8 +1,39 @@
 use crate::nvme::error::NvmeError;
+use crate::nvme::status::NvmeStatus;
 
 pub const SMART_LOG_BYTES: usize = 512;
 pub const IDENTIFY_BYTES: usize = 4096;
 pub const SELF_TEST_LOG_BYTES: usize = 564;
+pub const POWER_STATE_DESCRIPTOR_BYTES: usize = 32;
+pub const POWER_STATE_DESCRIPTORS: usize = 32;
+pub const LBA_FORMATS: usize = 64;
+pub const NAMESPACE_ID_LIST_ENTRIES: usize = 1024;
+pub const ENDURANCE_GROUP_LIST_ENTRIES: usize = 2047;
+pub const ENDURANCE_GROUP_LOG_BYTES: usize = 512;
+pub const MIN_MEMORY_PAGE_BYTES: usize = 4096;
+const MAX_DATA_TRANSFER_SHIFT: u8 = 8;
+pub const TELEMETRY_BLOCK_BYTES: usize = 512;
+pub const PERSISTENT_EVENT_LOG_HEADER_BYTES: usize = 512;
+pub const PERSISTENT_EVENT_HEADER_BYTES: usize = 24;
+pub const PERSISTENT_EVENT_SMART_SNAPSHOT: u8 = 0x01;
+pub const PERSISTENT_EVENT_FIRMWARE_COMMIT: u8 = 0x02;
+pub const PERSISTENT_EVENT_TIMESTAMP_CHANGE: u8 = 0x03;
+pub const PERSISTENT_EVENT_POWER_ON_RESET: u8 = 0x04;
+pub const PERSISTENT_EVENT_THERMAL_EXCURSION: u8 = 0x0D;
+pub const ZONE_REPORT_BYTES: usize = 4096;
+pub const ZONE_REPORT_HEADER_BYTES: usize = 64;
+pub const COMMAND_SET_ZONED: u8 = 0x02;
+pub const APST_DATA_BYTES: usize = 256;
+pub const APST_ENTRIES: usize = 32;
+pub const TIMESTAMP_DATA_BYTES: usize = 8;
+pub const HOST_MEMORY_BUFFER_DATA_BYTES: usize = 4096;
+pub const HOST_MEMORY_PAGE_BYTES: u64 = 4096;
+pub const FIRMWARE_SLOT_LOG_BYTES: usize = 512;
+pub const SELF_TEST_RESULT_BYTES: usize = 28;
+pub const SELF_TEST_RESULTS: usize = 20;
+pub const SELF_TEST_OPERATION_SHORT: u8 = 0x1;
+pub const SELF_TEST_OPERATION_EXTENDED: u8 = 0x2;
+pub const SELF_TEST_OPERATION_VENDOR_SPECIFIC: u8 = 0xE;
 pub const ERROR_LOG_ENTRY_BYTES: usize = 64;
 pub const ERROR_LOG_ENTRIES: usize = 16;
 pub const ERROR_LOG_BYTES: usize = ERROR_LOG_ENTRY_BYTES * ERROR_LOG_ENTRIES;
@@ -126,56 +157,1042 @@ impl SmartLog {
 
 #[derive(Clone, Debug)]
 pub struct IdentifyController {
+    pub vendor_id: u16,
+    pub subsystem_vendor_id: u16,
     pub serial: String,
     pub model: String,
     pub firmware_revision: String,
+    pub ieee_oui: u32,
+    pub mdts: u8,
+    pub controller_id: u16,
+    pub version: u32,
+    pub ctratt: u32,
+    pub oacs: u16,
+    pub lpa: u8,
+    pub npss: u8,
+    pub apsta: u8,
+    pub wctemp_kelvin: u16,
+    pub cctemp_kelvin: u16,
+    pub hmpre: u32,
+    pub hmmin: u32,
+    pub tnvmcap: u128,
+    pub unvmcap: u128,
+    pub endgidmax: u16,
+    pub number_of_namespaces: u32,
+    pub oncs: u16,
+    pub vwc: u8,
+    pub subsystem_nqn: String,
+    pub power_states: Vec<PowerStateDescriptor>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PowerStateDescriptor {
+    pub max_power_watts: f64,
+    pub non_operational: bool,
+    pub entry_latency_us: u32,
+    pub exit_latency_us: u32,
+    pub relative_read_throughput: u8,
+    pub relative_read_latency: u8,
+    pub relative_write_throughput: u8,
+    pub relative_write_latency: u8,
+    pub idle_power_watts: Option<f64>,
+    pub active_power_watts: Option<f64>,
+}
+
+impl PowerStateDescriptor {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != POWER_STATE_DESCRIPTOR_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: POWER_STATE_DESCRIPTOR_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let flags = read_u8(bytes, 3)?;
+        let max_power_scale = if (flags & (1 << 0)) != 0 {
+            0.0001
+        } else {
+            0.01
+        };
+
+        Ok(Self {
+            max_power_watts: f64::from(read_u16_le(bytes, 0)?) * max_power_scale,
+            non_operational: (flags & (1 << 1)) != 0,
+            entry_latency_us: read_u32_le(bytes, 4)?,
+            exit_latency_us: read_u32_le(bytes, 8)?,
+            relative_read_throughput: read_u8(bytes, 12)? & 0x1F,
+            relative_read_latency: read_u8(bytes, 13)? & 0x1F,
+            relative_write_throughput: read_u8(bytes, 14)? & 0x1F,
+            relative_write_latency: read_u8(bytes, 15)? & 0x1F,
+            idle_power_watts: scaled_power(read_u16_le(bytes, 16)?, read_u8(bytes, 18)? >> 6),
+            active_power_watts: scaled_power(read_u16_le(bytes, 20)?, read_u8(bytes, 22)? >> 6),
+        })
+    }
+}
+
+fn scaled_power(value: u16, scale: u8) -> Option<f64> {
+    match scale {
+        0b01 => Some(f64::from(value) * 0.0001),
+        0b10 => Some(f64::from(value) * 0.01),
+        _ => None,
+    }
 }
 
 impl IdentifyController {
     pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
-        if bytes.len() != IDENTIFY_BYTES {
+        if bytes.len() != IDENTIFY_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: IDENTIFY_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let oui = slice::<3>(bytes, 73)?;
+        let npss = read_u8(bytes, 263)?;
+
+        let mut power_states = Vec::new();
+        let mut state = 0_usize;
+        while state <= usize::from(npss) && state < POWER_STATE_DESCRIPTORS {
+            let offset = 2048 + (state * POWER_STATE_DESCRIPTOR_BYTES);
+            power_states.push(PowerStateDescriptor::parse(slice::<
+                POWER_STATE_DESCRIPTOR_BYTES,
+            >(bytes, offset)?)?);
+            state += 1;
+        }
+
+        Ok(Self {
+            vendor_id: read_u16_le(bytes, 0)?,
+            subsystem_vendor_id: read_u16_le(bytes, 2)?,
+            serial: trim_nvme_ascii(slice::<20>(bytes, 4)?),
+            model: trim_nvme_ascii(slice::<40>(bytes, 24)?),
+            firmware_revision: trim_nvme_ascii(slice::<8>(bytes, 64)?),
+            ieee_oui: u32::from(oui[0]) | (u32::from(oui[1]) << 8) | (u32::from(oui[2]) << 16),
+            mdts: read_u8(bytes, 77)?,
+            controller_id: read_u16_le(bytes, 78)?,
+            version: read_u32_le(bytes, 80)?,
+            ctratt: read_u32_le(bytes, 96)?,
+            oacs: read_u16_le(bytes, 256)?,
+            lpa: read_u8(bytes, 261)?,
+            npss,
+            apsta: read_u8(bytes, 265)?,
+            wctemp_kelvin: read_u16_le(bytes, 266)?,
+            cctemp_kelvin: read_u16_le(bytes, 268)?,
+            hmpre: read_u32_le(bytes, 272)?,
+            hmmin: read_u32_le(bytes, 276)?,
+            tnvmcap: read_u128_le(bytes, 280)?,
+            unvmcap: read_u128_le(bytes, 296)?,
+            endgidmax: read_u16_le(bytes, 340)?,
+            number_of_namespaces: read_u32_le(bytes, 516)?,
+            oncs: read_u16_le(bytes, 520)?,
+            vwc: read_u8(bytes, 525)?,
+            subsystem_nqn: trim_nvme_ascii(slice::<256>(bytes, 768)?),
+            power_states,
+        })
+    }
+
+    pub fn version_string(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            self.version >> 16,
+            (self.version >> 8) & 0xFF,
+            self.version & 0xFF
+        )
+    }
+
+    pub fn warning_temperature_celsius(&self) -> Option<f64> {
+        kelvin_to_celsius(self.wctemp_kelvin)
+    }
+
+    pub fn critical_temperature_celsius(&self) -> Option<f64> {
+        kelvin_to_celsius(self.cctemp_kelvin)
+    }
+
+    pub fn version_at_least(&self, major: u16, minor: u8) -> bool {
+        (self.version >> 8) >= ((u32::from(major) << 8) | u32::from(minor))
+    }
+
+    pub fn max_data_transfer_bytes(&self) -> Option<usize> {
+        if self.mdts == 0 {
+            return None;
+        }
+        // MDTS is a power of two in units of CAP.MPSMIN, which is a controller register that Identify
+        // does not report. Assume the 4 KiB minimum and cap the result at 1 MiB per command.
+        Some(MIN_MEMORY_PAGE_BYTES << self.mdts.min(MAX_DATA_TRANSFER_SHIFT))
+    }
+
+    pub fn supports_endurance_groups(&self) -> bool {
+        (self.ctratt & (1 << 4)) != 0
+    }
+
+    pub fn supports_firmware_download(&self) -> bool {
+        (self.oacs & (1 << 2)) != 0
+    }
+
+    pub fn supports_namespace_management(&self) -> bool {
+        (self.oacs & (1 << 3)) != 0
+    }
+
+    pub fn supports_self_test(&self) -> bool {
+        (self.oacs & (1 << 4)) != 0
+    }
+
+    pub fn supports_per_namespace_smart(&self) -> bool {
+        (self.lpa & (1 << 0)) != 0
+    }
+
+    pub fn supports_extended_log_page(&self) -> bool {
+        (self.lpa & (1 << 2)) != 0
+    }
+
+    pub fn supports_telemetry(&self) -> bool {
+        (self.lpa & (1 << 3)) != 0
+    }
+
+    pub fn supports_persistent_event_log(&self) -> bool {
+        (self.lpa & (1 << 4)) != 0
+    }
+
+    pub fn supports_telemetry_data_area_4(&self) -> bool {
+        (self.lpa & (1 << 6)) != 0
+    }
+
+    pub fn volatile_write_cache_present(&self) -> bool {
+        (self.vwc & (1 << 0)) != 0
+    }
+
+    pub fn host_memory_buffer_preferred_bytes(&self) -> u64 {
+        u64::from(self.hmpre) * HOST_MEMORY_PAGE_BYTES
+    }
+
+    pub fn host_memory_buffer_minimum_bytes(&self) -> u64 {
+        u64::from(self.hmmin) * HOST_MEMORY_PAGE_BYTES
+    }
+
+    pub fn supports_apst(&self) -> bool {
+        (self.apsta & (1 << 0)) != 0
+    }
+
+    pub fn supports_timestamp(&self) -> bool {
+        (self.oncs & (1 << 6)) != 0
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FirmwareSlot {
+    pub slot: u8,
+    pub revision: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct FirmwareSlotLog {
+    pub active_slot: u8,
+    pub next_reset_slot: Option<u8>,
+    pub slots: Vec<FirmwareSlot>,
+}
+
+impl FirmwareSlotLog {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != FIRMWARE_SLOT_LOG_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: FIRMWARE_SLOT_LOG_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let afi = read_u8(bytes, 0)?;
+        let next_reset_slot = (afi >> 4) & 0x07;
+
+        let mut slots = Vec::new();
+        let mut slot = 1_u8;
+        while slot <= 7 {
+            let offset = 8 + (usize::from(slot - 1) * 8);
+            let revision = trim_nvme_ascii(slice::<8>(bytes, offset)?);
+            if !revision.is_empty() {
+                slots.push(FirmwareSlot { slot, revision });
+            }
+            slot += 1;
+        }
+
+        Ok(Self {
+            active_slot: afi & 0x07,
+            next_reset_slot: (next_reset_slot != 0).then_some(next_reset_slot),
+            slots,
+        })
+    }
+
+    pub fn pending_activation(&self) -> bool {
+        self.next_reset_slot
+            .is_some_and(|slot| slot != self.active_slot)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IdentifyNamespace {
+    pub nsze: u64,
+    pub ncap: u64,
+    pub nuse: u64,
+    pub nsfeat: u8,
+    pub flbas: u8,
+    pub mc: u8,
+    pub dpc: u8,
+    pub dps: u8,
+    pub nmic: u8,
+    pub nvmcap: u128,
+    pub nguid: [u8; 16],
+    pub eui64: [u8; 8],
+    pub lba_formats: Vec<LbaFormat>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LbaFormat {
+    pub metadata_size: u16,
+    pub data_size_shift: u8,
+    pub relative_performance: u8,
+}
+
+impl LbaFormat {
+    pub fn parse(value: u32) -> Self {
+        Self {
+            metadata_size: (value & 0xFFFF) as u16,
+            data_size_shift: ((value >> 16) & 0xFF) as u8,
+            relative_performance: ((value >> 24) & 0x03) as u8,
+        }
+    }
+
+    pub fn data_size_bytes(&self) -> Option<u64> {
+        if self.data_size_shift < 9 || self.data_size_shift > 63 {
+            return None;
+        }
+        Some(1_u64 << self.data_size_shift)
+    }
+
+    pub fn relative_performance_name(&self) -> &'static str {
+        match self.relative_performance {
+            0 => "best",
+            1 => "better",
+            2 => "good",
+            _ => "degraded",
+        }
+    }
+}
+
+impl IdentifyNamespace {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != IDENTIFY_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: IDENTIFY_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let nlbaf = read_u8(bytes, 25)?;
+        let mut lba_formats = Vec::new();
+        let mut format = 0_usize;
+        while format <= usize::from(nlbaf) && format < LBA_FORMATS {
+            lba_formats.push(LbaFormat::parse(read_u32_le(bytes, 128 + (format * 4))?));
+            format += 1;
+        }
+
+        let mut nguid = [0_u8; 16];
+        nguid.copy_from_slice(slice::<16>(bytes, 104)?);
+        let mut eui64 = [0_u8; 8];
+        eui64.copy_from_slice(slice::<8>(bytes, 120)?);
+
+        Ok(Self {
+            nsze: read_u64_le(bytes, 0)?,
+            ncap: read_u64_le(bytes, 8)?,
+            nuse: read_u64_le(bytes, 16)?,
+            nsfeat: read_u8(bytes, 24)?,
+            flbas: read_u8(bytes, 26)?,
+            mc: read_u8(bytes, 27)?,
+            dpc: read_u8(bytes, 28)?,
+            dps: read_u8(bytes, 29)?,
+            nmic: read_u8(bytes, 30)?,
+            nvmcap: read_u128_le(bytes, 48)?,
+            nguid,
+            eui64,
+            lba_formats,
+        })
+    }
+
+    pub fn formatted_lba_index(&self) -> usize {
+        usize::from(self.flbas & 0x0F) | (usize::from((self.flbas >> 5) & 0x03) << 4)
+    }
+
+    pub fn formatted_lba_format(&self) -> Option<&LbaFormat> {
+        self.lba_formats.get(self.formatted_lba_index())
+    }
+
+    pub fn lba_size_bytes(&self) -> Option<u64> {
+        self.formatted_lba_format()
+            .and_then(|format| format.data_size_bytes())
+    }
+
+    pub fn size_bytes(&self) -> Option<u64> {
+        self.lba_size_bytes()
+            .map(|lba_size| self.nsze.saturating_mul(lba_size))
+    }
+
+    pub fn capacity_bytes(&self) -> Option<u64> {
+        self.lba_size_bytes()
+            .map(|lba_size| self.ncap.saturating_mul(lba_size))
+    }
+
+    pub fn utilization_bytes(&self) -> Option<u64> {
+        self.lba_size_bytes()
+            .map(|lba_size| self.nuse.saturating_mul(lba_size))
+    }
+
+    pub fn metadata_extended_lba(&self) -> bool {
+        (self.flbas & (1 << 4)) != 0
+    }
+
+    pub fn protection_type(&self) -> u8 {
+        self.dps & 0x07
+    }
+
+    pub fn protection_type_name(&self) -> &'static str {
+        match self.protection_type() {
+            0 => "none",
+            1 => "type1",
+            2 => "type2",
+            3 => "type3",
+            _ => "reserved",
+        }
+    }
+
+    pub fn protection_info_first(&self) -> bool {
+        (self.dps & (1 << 3)) != 0
+    }
+
+    pub fn shared(&self) -> bool {
+        (self.nmic & (1 << 0)) != 0
+    }
+
+    pub fn nguid_string(&self) -> Option<String> {
+        hex_identifier(&self.nguid)
+    }
+
+    pub fn eui64_string(&self) -> Option<String> {
+        hex_identifier(&self.eui64)
+    }
+}
+
+fn hex_identifier(bytes: &[u8]) -> Option<String> {
+    if bytes.iter().all(|value| *value == 0) {
+        return None;
+    }
+    Some(bytes.iter().map(|value| format!("{:02x}", value)).collect())
+}
+
+#[derive(Clone, Debug)]
+pub struct NamespaceIdList {
+    pub nsids: Vec<u32>,
+}
+
+impl NamespaceIdList {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != IDENTIFY_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: IDENTIFY_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut nsids = Vec::new();
+        for index in 0..NAMESPACE_ID_LIST_ENTRIES {
+            let nsid = read_u32_le(bytes, index * 4)?;
+            if nsid == 0 {
+                break;
+            }
+            nsids.push(nsid);
+        }
+
+        Ok(Self { nsids })
+    }
+
+    pub fn full(&self) -> bool {
+        self.nsids.len() == NAMESPACE_ID_LIST_ENTRIES
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EnduranceGroupList {
+    pub ids: Vec<u16>,
+}
+
+impl EnduranceGroupList {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != IDENTIFY_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: IDENTIFY_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let count = usize::from(read_u16_le(bytes, 0)?).min(ENDURANCE_GROUP_LIST_ENTRIES);
+        let mut ids = Vec::with_capacity(count);
+        for index in 0..count {
+            ids.push(read_u16_le(bytes, 2 + index * 2)?);
+        }
+
+        Ok(Self { ids })
+    }
+
+    pub fn full(&self) -> bool {
+        self.ids.len() == ENDURANCE_GROUP_LIST_ENTRIES
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EnduranceGroupLog {
+    pub critical_warning: u8,
+    pub avail_spare: u8,
+    pub spare_thresh: u8,
+    pub percent_used: u8,
+    pub endurance_estimate: u128,
+    pub data_units_read: u128,
+    pub data_units_written: u128,
+    pub media_units_written: u128,
+    pub host_read_commands: u128,
+    pub host_write_commands: u128,
+    pub media_errors: u128,
+    pub num_err_log_entries: u128,
+}
+
+impl EnduranceGroupLog {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != ENDURANCE_GROUP_LOG_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: ENDURANCE_GROUP_LOG_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(Self {
+            critical_warning: read_u8(bytes, 0)?,
+            avail_spare: read_u8(bytes, 3)?,
+            spare_thresh: read_u8(bytes, 4)?,
+            percent_used: read_u8(bytes, 5)?,
+            endurance_estimate: read_u128_le(bytes, 32)?,
+            data_units_read: read_u128_le(bytes, 48)?,
+            data_units_written: read_u128_le(bytes, 64)?,
+            media_units_written: read_u128_le(bytes, 80)?,
+            host_read_commands: read_u128_le(bytes, 96)?,
+            host_write_commands: read_u128_le(bytes, 112)?,
+            media_errors: read_u128_le(bytes, 128)?,
+            num_err_log_entries: read_u128_le(bytes, 144)?,
+        })
+    }
+
+    pub fn available_spare_ratio(&self) -> f64 {
+        f64::from(self.avail_spare) / 100.0
+    }
+
+    pub fn available_spare_threshold_ratio(&self) -> f64 {
+        f64::from(self.spare_thresh) / 100.0
+    }
+
+    pub fn percent_used_ratio(&self) -> f64 {
+        f64::from(self.percent_used) / 100.0
+    }
+
+    pub fn endurance_estimate_bytes(&self) -> f64 {
+        self.endurance_estimate as f64 * 1_000_000_000.0
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TelemetryLogHeader {
+    pub lid: u8,
+    pub ieee_oui: u32,
+    pub data_area_last_blocks: [u32; 4],
+    pub host_generation_number: u8,
+    pub controller_data_available: bool,
+    pub controller_generation_number: u8,
+    pub reason_identifier: [u8; 128],
+}
+
+impl TelemetryLogHeader {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() < TELEMETRY_BLOCK_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: TELEMETRY_BLOCK_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut reason_identifier = [0_u8; 128];
+        reason_identifier.copy_from_slice(slice::<128>(bytes, 384)?);
+
+        Ok(Self {
+            lid: read_u8(bytes, 0)?,
+            ieee_oui: read_uint_le(bytes, 5, 3)? as u32,
+            data_area_last_blocks: [
+                u32::from(read_u16_le(bytes, 8)?),
+                u32::from(read_u16_le(bytes, 10)?),
+                u32::from(read_u16_le(bytes, 12)?),
+                read_u32_le(bytes, 16)?,
+            ],
+            host_generation_number: read_u8(bytes, 381)?,
+            controller_data_available: read_u8(bytes, 382)? != 0,
+            controller_generation_number: read_u8(bytes, 383)?,
+            reason_identifier,
+        })
+    }
+
+    pub fn data_area_bytes(&self, data_area: usize) -> u64 {
+        let last_block = self.data_area_last_blocks[..data_area.clamp(1, 4)]
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0);
+        (u64::from(last_block) + 1) * TELEMETRY_BLOCK_BYTES as u64
+    }
+
+    pub fn reason_identifier_string(&self) -> String {
+        hex_identifier(&self.reason_identifier).unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TelemetryLog {
+    pub header: TelemetryLogHeader,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PersistentEventLogHeader {
+    pub total_events: u32,
+    pub total_log_length: u64,
+    pub revision: u8,
+    pub header_length: u16,
+    pub timestamp_ms: u64,
+    pub power_cycle_count: u64,
+    pub generation_number: u16,
+    pub supported_events: [u8; 32],
+}
+
+impl PersistentEventLogHeader {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() < PERSISTENT_EVENT_LOG_HEADER_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: PERSISTENT_EVENT_LOG_HEADER_BYTES,
+                actual: bytes.len(),
+            });
+        }
+        let lid = read_u8(bytes, 0)?;
+        if lid != 0x0D {
+            return Err(NvmeError::InvalidData(format!(
+                "persistent event log header carries log identifier 0x{:02x}",
+                lid
+            )));
+        }
+
+        let mut supported_events = [0_u8; 32];
+        supported_events.copy_from_slice(slice::<32>(bytes, 480)?);
+
+        Ok(Self {
+            total_events: read_u32_le(bytes, 4)?,
+            total_log_length: read_u64_le(bytes, 8)?,
+            revision: read_u8(bytes, 16)?,
+            header_length: read_u16_le(bytes, 18)?,
+            timestamp_ms: read_u64_le(bytes, 20)? & 0xFFFF_FFFF_FFFF,
+            power_cycle_count: read_u64_le(bytes, 44)?,
+            generation_number: read_u16_le(bytes, 372)?,
+            supported_events,
+        })
+    }
+
+    pub fn supports_event(&self, event_type: u8) -> bool {
+        let byte = usize::from(event_type / 8);
+        (self.supported_events[byte] & (1 << (event_type % 8))) != 0
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PersistentEvent {
+    pub event_type: u8,
+    pub revision: u8,
+    pub controller_id: u16,
+    pub timestamp_ms: u64,
+    pub data: Vec<u8>,
+}
+
+impl PersistentEvent {
+    pub fn type_name(&self) -> String {
+        persistent_event_type_name(self.event_type)
+    }
+
+    pub fn detail(&self) -> String {
+        let data = self.data.as_slice();
+        match self.event_type {
+            PERSISTENT_EVENT_FIRMWARE_COMMIT if data.len() >= 16 => format!(
+                "old_firmware={} new_firmware={}",
+                trim_nvme_ascii(&data[0..8]),
+                trim_nvme_ascii(&data[8..16])
+            ),
+            PERSISTENT_EVENT_TIMESTAMP_CHANGE if data.len() >= 8 => format!(
+                "previous_timestamp_ms={}",
+                u64::from_le_bytes([data[0], data[1], data[2], data[3], data[4], data[5], 0, 0])
+            ),
+            PERSISTENT_EVENT_POWER_ON_RESET if data.len() >= 8 => {
+                format!("firmware={}", trim_nvme_ascii(&data[0..8]))
+            }
+            PERSISTENT_EVENT_THERMAL_EXCURSION if data.len() >= 2 => format!(
+                "over_temperature_celsius={} threshold_celsius={}",
+                data[0], data[1]
+            ),
+            _ => String::new(),
+        }
+    }
+}
+
+pub fn persistent_event_type_name(event_type: u8) -> String {
+    match event_type {
+        PERSISTENT_EVENT_SMART_SNAPSHOT => "smart_snapshot".to_string(),
+        PERSISTENT_EVENT_FIRMWARE_COMMIT => "firmware_commit".to_string(),
+        PERSISTENT_EVENT_TIMESTAMP_CHANGE => "timestamp_change".to_string(),
+        PERSISTENT_EVENT_POWER_ON_RESET => "power_on_reset".to_string(),
+        0x05 => "hardware_error".to_string(),
+        0x06 => "change_namespace".to_string(),
+        0x07 => "format_start".to_string(),
+        0x08 => "format_completion".to_string(),
+        0x09 => "sanitize_start".to_string(),
+        0x0A => "sanitize_completion".to_string(),
+        0x0B => "set_feature".to_string(),
+        0x0C => "telemetry_log_create".to_string(),
+        PERSISTENT_EVENT_THERMAL_EXCURSION => "thermal_excursion".to_string(),
+        0xDE => "vendor_specific".to_string(),
+        0xDF => "tcg_defined".to_string(),
+        other => format!("0x{:02x}", other),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PersistentEventLog {
+    pub header: PersistentEventLogHeader,
+    pub events: Vec<PersistentEvent>,
+}
+
+impl PersistentEventLog {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        let header = PersistentEventLogHeader::parse(bytes)?;
+
+        let mut events = Vec::new();
+        let mut offset = PERSISTENT_EVENT_LOG_HEADER_BYTES;
+        while events.len() < header.total_events as usize
+            && offset + PERSISTENT_EVENT_HEADER_BYTES <= bytes.len()
+        {
+            let header_end = offset + usize::from(read_u8(bytes, offset + 2)?) + 3;
+            let vendor_length = usize::from(read_u16_le(bytes, offset + 20)?);
+            let event_length = usize::from(read_u16_le(bytes, offset + 22)?);
+            let end = header_end + event_length;
+            let data = bytes
+                .get(header_end + vendor_length.min(event_length)..end)
+                .ok_or_else(|| {
+                    NvmeError::Parse(format!(
+                        "persistent event at offset {} overruns log of length {}",
+                        offset,
+                        bytes.len()
+                    ))
+                })?;
+            events.push(PersistentEvent {
+                event_type: read_u8(bytes, offset)?,
+                revision: read_u8(bytes, offset + 1)?,
+                controller_id: read_u16_le(bytes, offset + 4)?,
+                timestamp_ms: read_u64_le(bytes, offset + 6)? & 0xFFFF_FFFF_FFFF,
+                data: data.to_vec(),
+            });
+            offset = end;
+        }
+
+        Ok(Self { header, events })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NamespaceIdentificationDescriptors {
+    pub eui64: Option<[u8; 8]>,
+    pub nguid: Option<[u8; 16]>,
+    pub uuid: Option<[u8; 16]>,
+    pub csi: Option<u8>,
+}
+
+impl NamespaceIdentificationDescriptors {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != IDENTIFY_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: IDENTIFY_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut descriptors = Self::default();
+        let mut offset = 0_usize;
+        while offset + 4 <= bytes.len() {
+            let kind = read_u8(bytes, offset)?;
+            let length = usize::from(read_u8(bytes, offset + 1)?);
+            if kind == 0 {
+                break;
+            }
+
+            let value = offset + 4;
+            match (kind, length) {
+                (0x01, 8) => {
+                    let mut eui64 = [0_u8; 8];
+                    eui64.copy_from_slice(slice::<8>(bytes, value)?);
+                    descriptors.eui64 = Some(eui64);
+                }
+                (0x02, 16) => {
+                    let mut nguid = [0_u8; 16];
+                    nguid.copy_from_slice(slice::<16>(bytes, value)?);
+                    descriptors.nguid = Some(nguid);
+                }
+                (0x03, 16) => {
+                    let mut uuid = [0_u8; 16];
+                    uuid.copy_from_slice(slice::<16>(bytes, value)?);
+                    descriptors.uuid = Some(uuid);
+                }
+                (0x04, 1) => descriptors.csi = Some(read_u8(bytes, value)?),
+                _ => {}
+            }
+            offset = value + length;
+        }
+
+        Ok(descriptors)
+    }
+
+    pub fn eui64_string(&self) -> Option<String> {
+        self.eui64.as_ref().and_then(|value| hex_identifier(value))
+    }
+
+    pub fn nguid_string(&self) -> Option<String> {
+        self.nguid.as_ref().and_then(|value| hex_identifier(value))
+    }
+
+    pub fn uuid_string(&self) -> Option<String> {
+        let hex = hex_identifier(self.uuid.as_ref()?)?;
+        Some(format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        ))
+    }
+
+    pub fn csi_name(&self) -> Option<String> {
+        self.csi.map(command_set_name)
+    }
+}
+
+pub fn command_set_name(csi: u8) -> String {
+    match csi {
+        0x00 => "nvm".to_string(),
+        0x01 => "key_value".to_string(),
+        0x02 => "zoned".to_string(),
+        other => format!("0x{:02x}", other),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ZoneFormat {
+    pub zone_size_lbas: u64,
+    pub descriptor_extension_bytes: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct ZonedNamespace {
+    pub zoc: u16,
+    pub ozcs: u16,
+    pub max_active_resources: Option<u32>,
+    pub max_open_resources: Option<u32>,
+    pub zone_formats: Vec<ZoneFormat>,
+}
+
+impl ZonedNamespace {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != IDENTIFY_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: IDENTIFY_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut zone_formats = Vec::with_capacity(LBA_FORMATS);
+        for format in 0..LBA_FORMATS {
+            let offset = 2816 + (format * 16);
+            zone_formats.push(ZoneFormat {
+                zone_size_lbas: read_u64_le(bytes, offset)?,
+                descriptor_extension_bytes: u32::from(read_u8(bytes, offset + 8)?) * 64,
+            });
+        }
+
+        Ok(Self {
+            zoc: read_u16_le(bytes, 0)?,
+            ozcs: read_u16_le(bytes, 2)?,
+            max_active_resources: zero_based_limit(read_u32_le(bytes, 4)?),
+            max_open_resources: zero_based_limit(read_u32_le(bytes, 8)?),
+            zone_formats,
+        })
+    }
+
+    pub fn zone_size_lbas(&self, namespace: &IdentifyNamespace) -> Option<u64> {
+        self.zone_formats
+            .get(namespace.formatted_lba_index())
+            .map(|format| format.zone_size_lbas)
+            .filter(|value| *value > 0)
+    }
+
+    pub fn zone_size_bytes(&self, namespace: &IdentifyNamespace) -> Option<u64> {
+        let lba_size = namespace.lba_size_bytes()?;
+        self.zone_size_lbas(namespace)
+            .map(|zone_size| zone_size.saturating_mul(lba_size))
+    }
+
+    pub fn zone_count(&self, namespace: &IdentifyNamespace) -> Option<u64> {
+        self.zone_size_lbas(namespace)
+            .map(|zone_size| namespace.nsze / zone_size)
+    }
+}
+
+fn zero_based_limit(value: u32) -> Option<u32> {
+    if value == u32::MAX {
+        None
+    } else {
+        Some(value + 1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ZoneState {
+    Empty,
+    ImplicitlyOpened,
+    ExplicitlyOpened,
+    Closed,
+    Full,
+    ReadOnly,
+    Offline,
+}
+
+impl ZoneState {
+    pub const ALL: [ZoneState; 7] = [
+        ZoneState::Empty,
+        ZoneState::ImplicitlyOpened,
+        ZoneState::ExplicitlyOpened,
+        ZoneState::Closed,
+        ZoneState::Full,
+        ZoneState::ReadOnly,
+        ZoneState::Offline,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ZoneState::Empty => "empty",
+            ZoneState::ImplicitlyOpened => "implicitly_opened",
+            ZoneState::ExplicitlyOpened => "explicitly_opened",
+            ZoneState::Closed => "closed",
+            ZoneState::Full => "full",
+            ZoneState::ReadOnly => "read_only",
+            ZoneState::Offline => "offline",
+        }
+    }
+
+    pub fn report_filter(&self) -> u8 {
+        match self {
+            ZoneState::Empty => 0x01,
+            ZoneState::ImplicitlyOpened => 0x02,
+            ZoneState::ExplicitlyOpened => 0x03,
+            ZoneState::Closed => 0x04,
+            ZoneState::Full => 0x05,
+            ZoneState::ReadOnly => 0x06,
+            ZoneState::Offline => 0x07,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ZoneReportHeader {
+    pub number_of_zones: u64,
+}
+
+impl ZoneReportHeader {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() < ZONE_REPORT_HEADER_BYTES {
             return Err(NvmeError::UnexpectedSize {
-                expected: IDENTIFY_BYTES,
+                expected: ZONE_REPORT_HEADER_BYTES,
                 actual: bytes.len(),
             });
         }
 
         Ok(Self {
-            serial: trim_nvme_ascii(slice::<20>(bytes, 4)?),
-            model: trim_nvme_ascii(slice::<40>(bytes, 24)?),
-            firmware_revision: trim_nvme_ascii(slice::<8>(bytes, 64)?),
+            number_of_zones: read_u64_le(bytes, 0)?,
         })
     }
 }
 
 #[derive(Clone, Copy, Debug)]
-pub struct IdentifyNamespace {
-    pub nsze: u64,
-    pub ncap: u64,
-    pub nuse: u64,
+pub struct ErrorLogEntry {
+    pub error_count: u64,
+    pub submission_queue_id: u16,
+    pub command_id: u16,
+    pub status: NvmeStatus,
+    pub phase_tag: bool,
+    pub parameter_error_byte: Option<u8>,
+    pub parameter_error_bit: Option<u8>,
+    pub lba: u64,
+    pub nsid: u32,
+    pub vendor_specific_log_page: u8,
+    pub transport_type: u8,
+    pub command_specific: u64,
+    pub transport_specific: u16,
 }
 
-impl IdentifyNamespace {
+impl ErrorLogEntry {
     pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
-        if bytes.len() != IDENTIFY_BYTES {
+        if bytes.len() != ERROR_LOG_ENTRY_BYTES {
             return Err(NvmeError::UnexpectedSize {
-                expected: IDENTIFY_BYTES,
+                expected: ERROR_LOG_ENTRY_BYTES,
                 actual: bytes.len(),
             });
         }
 
+        let status_field = read_u16_le(bytes, 12)?;
+        let parameter_error_location = read_u16_le(bytes, 14)?;
+        let (parameter_error_byte, parameter_error_bit) = if parameter_error_location == 0xFFFF {
+            (None, None)
+        } else {
+            (
+                Some((parameter_error_location & 0xFF) as u8),
+                Some(((parameter_error_location >> 8) & 0x07) as u8),
+            )
+        };
+
         Ok(Self {
-            nsze: read_u64_le(bytes, 0)?,
-            ncap: read_u64_le(bytes, 8)?,
-            nuse: read_u64_le(bytes, 16)?,
+            error_count: read_u64_le(bytes, 0)?,
+            submission_queue_id: read_u16_le(bytes, 8)?,
+            command_id: read_u16_le(bytes, 10)?,
+            status: NvmeStatus::from_raw(status_field >> 1),
+            phase_tag: (status_field & 0x01) != 0,
+            parameter_error_byte,
+            parameter_error_bit,
+            lba: read_u64_le(bytes, 16)?,
+            nsid: read_u32_le(bytes, 24)?,
+            vendor_specific_log_page: read_u8(bytes, 28)?,
+            transport_type: read_u8(bytes, 29)?,
+            command_specific: read_u64_le(bytes, 32)?,
+            transport_specific: read_u16_le(bytes, 40)?,
         })
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct ErrorLogSummary {
     pub non_zero_entries: u64,
     pub max_error_count: u64,
+    pub entries: Vec<ErrorLogEntry>,
 }
 
 impl ErrorLogSummary {
@@ -190,30 +1207,108 @@ impl ErrorLogSummary {
 
         let mut non_zero_entries = 0_u64;
         let mut max_error_count = 0_u64;
+        let mut entries = Vec::new();
         let mut offset = 0_usize;
 
         while offset < bytes.len() {
-            let error_count = read_u64_le(bytes, offset)?;
-            if error_count > 0 {
+            let entry = ErrorLogEntry::parse(slice::<ERROR_LOG_ENTRY_BYTES>(bytes, offset)?)?;
+            if entry.error_count > 0 {
                 non_zero_entries += 1;
+                entries.push(entry);
             }
-            if error_count > max_error_count {
-                max_error_count = error_count;
+            if entry.error_count > max_error_count {
+                max_error_count = entry.error_count;
             }
             offset += ERROR_LOG_ENTRY_BYTES;
         }
 
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.error_count));
+
         Ok(Self {
             non_zero_entries,
             max_error_count,
+            entries,
         })
     }
 }
 
 #[derive(Clone, Copy, Debug)]
+pub struct SelfTestResult {
+    pub operation: u8,
+    pub result: u8,
+    pub segment_number: u8,
+    pub power_on_hours: u64,
+    pub nsid: Option<u32>,
+    pub failing_lba: Option<u64>,
+    pub status_code_type: Option<u8>,
+    pub status_code: Option<u8>,
+    pub vendor_specific: u16,
+}
+
+impl SelfTestResult {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != SELF_TEST_RESULT_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: SELF_TEST_RESULT_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let status = read_u8(bytes, 0)?;
+        let valid = read_u8(bytes, 2)?;
+        let nsid = read_u32_le(bytes, 12)?;
+        let failing_lba = read_u64_le(bytes, 16)?;
+        let status_code_type = read_u8(bytes, 24)? & 0x07;
+        let status_code = read_u8(bytes, 25)?;
+
+        Ok(Self {
+            operation: status >> 4,
+            result: status & 0x0F,
+            segment_number: read_u8(bytes, 1)?,
+            power_on_hours: read_u64_le(bytes, 4)?,
+            nsid: ((valid & (1 << 0)) != 0).then_some(nsid),
+            failing_lba: ((valid & (1 << 1)) != 0).then_some(failing_lba),
+            status_code_type: ((valid & (1 << 2)) != 0).then_some(status_code_type),
+            status_code: ((valid & (1 << 3)) != 0).then_some(status_code),
+            vendor_specific: read_u16_le(bytes, 26)?,
+        })
+    }
+
+    pub fn unused(&self) -> bool {
+        self.result == 0xF
+    }
+
+    pub fn failed(&self) -> bool {
+        matches!(self.result, 0x5..=0x7)
+    }
+
+    pub fn operation_name(&self) -> String {
+        self_test_operation_name(self.operation)
+    }
+
+    pub fn result_name(&self) -> &'static str {
+        match self.result {
+            0x0 => "no_error",
+            0x1 => "aborted_by_command",
+            0x2 => "aborted_by_controller_reset",
+            0x3 => "aborted_by_namespace_removal",
+            0x4 => "aborted_by_format",
+            0x5 => "fatal_error",
+            0x6 => "failed_unknown_segment",
+            0x7 => "failed_segment",
+            0x8 => "aborted_unknown_reason",
+            0x9 => "aborted_by_sanitize",
+            0xF => "unused",
+            _ => "reserved",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct SelfTestLogSummary {
     pub current_operation: u8,
     pub current_completion_ratio: f64,
+    pub results: Vec<SelfTestResult>,
 }
 
 impl SelfTestLogSummary {
@@ -225,12 +1320,217 @@ impl SelfTestLogSummary {
             });
         }
 
-        let current_operation = read_u8(bytes, 0)?;
-        let current_completion = read_u8(bytes, 1)?;
+        let current_operation = read_u8(bytes, 0)? & 0x0F;
+        let current_completion = read_u8(bytes, 1)? & 0x7F;
+
+        let mut results = Vec::new();
+        let mut index = 0_usize;
+        while index < SELF_TEST_RESULTS {
+            let offset = 4 + (index * SELF_TEST_RESULT_BYTES);
+            let result = SelfTestResult::parse(slice::<SELF_TEST_RESULT_BYTES>(bytes, offset)?)?;
+            if !result.unused() {
+                results.push(result);
+            }
+            index += 1;
+        }
 
         Ok(Self {
             current_operation,
             current_completion_ratio: f64::from(current_completion) / 100.0,
+            results,
+        })
+    }
+
+    pub fn last_result(&self, operation: u8) -> Option<&SelfTestResult> {
+        self.results
+            .iter()
+            .find(|result| result.operation == operation)
+    }
+}
+
+pub fn self_test_operation_name(operation: u8) -> String {
+    match operation {
+        SELF_TEST_OPERATION_SHORT => "short".to_string(),
+        SELF_TEST_OPERATION_EXTENDED => "extended".to_string(),
+        SELF_TEST_OPERATION_VENDOR_SPECIFIC => "vendor_specific".to_string(),
+        other => format!("0x{:x}", other),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Arbitration {
+    pub burst: Option<u32>,
+    pub low_priority_weight: u8,
+    pub medium_priority_weight: u8,
+    pub high_priority_weight: u8,
+}
+
+impl Arbitration {
+    pub fn parse(value: u32) -> Self {
+        let burst = value & 0x07;
+        Self {
+            burst: (burst != 0x07).then(|| 1 << burst),
+            low_priority_weight: ((value >> 8) & 0xFF) as u8,
+            medium_priority_weight: ((value >> 16) & 0xFF) as u8,
+            high_priority_weight: ((value >> 24) & 0xFF) as u8,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PowerManagement {
+    pub power_state: u8,
+    pub workload_hint: u8,
+}
+
+impl PowerManagement {
+    pub fn parse(value: u32) -> Self {
+        Self {
+            power_state: (value & 0x1F) as u8,
+            workload_hint: ((value >> 5) & 0x07) as u8,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TemperatureThreshold {
+    pub threshold_kelvin: u16,
+}
+
+impl TemperatureThreshold {
+    pub fn parse(value: u32) -> Self {
+        Self {
+            threshold_kelvin: (value & 0xFFFF) as u16,
+        }
+    }
+
+    pub fn select(sensor: u8, under: bool) -> u32 {
+        (u32::from(under) << 20) | (u32::from(sensor & 0x0F) << 16)
+    }
+
+    pub fn celsius(&self) -> Option<f64> {
+        kelvin_to_celsius(self.threshold_kelvin)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct VolatileWriteCache {
+    pub enabled: bool,
+}
+
+impl VolatileWriteCache {
+    pub fn parse(value: u32) -> Self {
+        Self {
+            enabled: (value & 0x01) != 0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct HostMemoryBuffer {
+    pub enabled: bool,
+    pub memory_return: bool,
+    pub size_pages: u32,
+    pub descriptor_entries: u32,
+}
+
+impl HostMemoryBuffer {
+    pub fn parse(value: u32, bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != HOST_MEMORY_BUFFER_DATA_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: HOST_MEMORY_BUFFER_DATA_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(Self {
+            enabled: (value & (1 << 0)) != 0,
+            memory_return: (value & (1 << 1)) != 0,
+            size_pages: read_u32_le(bytes, 0)?,
+            descriptor_entries: read_u32_le(bytes, 12)?,
+        })
+    }
+
+    pub fn size_bytes(&self) -> u64 {
+        u64::from(self.size_pages) * HOST_MEMORY_PAGE_BYTES
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct NumberOfQueues {
+    pub submission_queues: u32,
+    pub completion_queues: u32,
+}
+
+impl NumberOfQueues {
+    pub fn parse(value: u32) -> Self {
+        Self {
+            submission_queues: (value & 0xFFFF) + 1,
+            completion_queues: (value >> 16) + 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ApstEntry {
+    pub idle_transition_power_state: u8,
+    pub idle_time_prior_to_transition_ms: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct AutonomousPowerStateTransition {
+    pub enabled: bool,
+    pub entries: Vec<ApstEntry>,
+}
+
+impl AutonomousPowerStateTransition {
+    pub fn parse(value: u32, bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != APST_DATA_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: APST_DATA_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut entries = Vec::with_capacity(APST_ENTRIES);
+        let mut index = 0_usize;
+        while index < APST_ENTRIES {
+            let entry = read_u64_le(bytes, index * 8)?;
+            entries.push(ApstEntry {
+                idle_transition_power_state: ((entry >> 3) & 0x1F) as u8,
+                idle_time_prior_to_transition_ms: ((entry >> 8) & 0xFF_FFFF) as u32,
+            });
+            index += 1;
+        }
+
+        Ok(Self {
+            enabled: (value & 0x01) != 0,
+            entries,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Timestamp {
+    pub milliseconds: u64,
+    pub synch: bool,
+    pub origin: u8,
+}
+
+impl Timestamp {
+    pub fn parse(bytes: &[u8]) -> Result<Self, NvmeError> {
+        if bytes.len() != TIMESTAMP_DATA_BYTES {
+            return Err(NvmeError::UnexpectedSize {
+                expected: TIMESTAMP_DATA_BYTES,
+                actual: bytes.len(),
+            });
+        }
+
+        let attributes = read_u8(bytes, 6)?;
+        Ok(Self {
+            milliseconds: read_u64_le(bytes, 0)? & 0xFFFF_FFFF_FFFF,
+            synch: (attributes & 0x01) != 0,
+            origin: (attributes >> 1) & 0x07,
         })
     }
 }
@@ -251,7 +1551,7 @@ fn kelvin_to_celsius(value: u16) -> Option<f64> {
     }
 }
 
-fn read_u8(bytes: &[u8], offset: usize) -> Result<u8, NvmeError> {
+pub(crate) fn read_u8(bytes: &[u8], offset: usize) -> Result<u8, NvmeError> {
     bytes.get(offset).copied().ok_or_else(|| {
         NvmeError::Parse(format!(
             "requested byte {} from buffer of length {}",
@@ -261,7 +1561,7 @@ fn read_u8(bytes: &[u8], offset: usize) -> Result<u8, NvmeError> {
     })
 }
 
-fn slice<const N: usize>(bytes: &[u8], offset: usize) -> Result<&[u8], NvmeError> {
+pub(crate) fn slice<const N: usize>(bytes: &[u8], offset: usize) -> Result<&[u8], NvmeError> {
     let end = offset.saturating_add(N);
     bytes.get(offset..end).ok_or_else(|| {
         NvmeError::Parse(format!(
@@ -273,28 +1573,49 @@ fn slice<const N: usize>(bytes: &[u8], offset: usize) -> Result<&[u8], NvmeError
     })
 }
 
-fn read_u16_le(bytes: &[u8], offset: usize) -> Result<u16, NvmeError> {
+pub(crate) fn read_u16_le(bytes: &[u8], offset: usize) -> Result<u16, NvmeError> {
     let src = slice::<2>(bytes, offset)?;
     let mut value = [0_u8; 2];
     value.copy_from_slice(src);
     Ok(u16::from_le_bytes(value))
 }
 
-fn read_u32_le(bytes: &[u8], offset: usize) -> Result<u32, NvmeError> {
+pub(crate) fn read_u32_le(bytes: &[u8], offset: usize) -> Result<u32, NvmeError> {
     let src = slice::<4>(bytes, offset)?;
     let mut value = [0_u8; 4];
     value.copy_from_slice(src);
     Ok(u32::from_le_bytes(value))
 }
 
-fn read_u64_le(bytes: &[u8], offset: usize) -> Result<u64, NvmeError> {
+pub(crate) fn read_u64_le(bytes: &[u8], offset: usize) -> Result<u64, NvmeError> {
     let src = slice::<8>(bytes, offset)?;
     let mut value = [0_u8; 8];
     value.copy_from_slice(src);
     Ok(u64::from_le_bytes(value))
 }
 
-fn read_u128_le(bytes: &[u8], offset: usize) -> Result<u128, NvmeError> {
+pub(crate) fn read_uint_le(bytes: &[u8], offset: usize, len: usize) -> Result<u64, NvmeError> {
+    if len > 8 {
+        return Err(NvmeError::Parse(format!(
+            "cannot read {} bytes into a u64",
+            len
+        )));
+    }
+    let end = offset.saturating_add(len);
+    let src = bytes.get(offset..end).ok_or_else(|| {
+        NvmeError::Parse(format!(
+            "requested range {}..{} from buffer of length {}",
+            offset,
+            end,
+            bytes.len()
+        ))
+    })?;
+    let mut value = [0_u8; 8];
+    value[..len].copy_from_slice(src);
+    Ok(u64::from_le_bytes(value))
+}
+
+pub(crate) fn read_u128_le(bytes: &[u8], offset: usize) -> Result<u128, NvmeError> {
     let src = slice::<16>(bytes, offset)?;
     let mut value = [0_u8; 16];
     value.copy_from_slice(src);
@@ -303,10 +1624,39 @@ fn read_u128_le(bytes: &[u8], offset: usize) -> Result<u128, NvmeError> {
 
 #[cfg(test)]
 mod tests {
+    use crate::nvme::status::StatusCodeType;
     use crate::nvme::types::trim_nvme_ascii;
+    use crate::nvme::types::Arbitration;
+    use crate::nvme::types::AutonomousPowerStateTransition;
+    use crate::nvme::types::EnduranceGroupList;
+    use crate::nvme::types::EnduranceGroupLog;
+    use crate::nvme::types::ErrorLogEntry;
     use crate::nvme::types::ErrorLogSummary;
+    use crate::nvme::types::FirmwareSlotLog;
+    use crate::nvme::types::HostMemoryBuffer;
+    use crate::nvme::types::IdentifyController;
+    use crate::nvme::types::IdentifyNamespace;
+    use crate::nvme::types::NamespaceIdList;
+    use crate::nvme::types::NamespaceIdentificationDescriptors;
+    use crate::nvme::types::NumberOfQueues;
+    use crate::nvme::types::PersistentEventLog;
+    use crate::nvme::types::SelfTestLogSummary;
     use crate::nvme::types::SmartLog;
+    use crate::nvme::types::TelemetryLogHeader;
+    use crate::nvme::types::TemperatureThreshold;
+    use crate::nvme::types::Timestamp;
+    use crate::nvme::types::ZoneReportHeader;
+    use crate::nvme::types::ZonedNamespace;
+    use crate::nvme::types::APST_DATA_BYTES;
+    use crate::nvme::types::ENDURANCE_GROUP_LOG_BYTES;
     use crate::nvme::types::ERROR_LOG_BYTES;
+    use crate::nvme::types::ERROR_LOG_ENTRY_BYTES;
+    use crate::nvme::types::FIRMWARE_SLOT_LOG_BYTES;
+    use crate::nvme::types::HOST_MEMORY_BUFFER_DATA_BYTES;
+    use crate::nvme::types::IDENTIFY_BYTES;
+    use crate::nvme::types::SELF_TEST_LOG_BYTES;
+    use crate::nvme::types::SELF_TEST_OPERATION_EXTENDED;
+    use crate::nvme::types::SELF_TEST_OPERATION_SHORT;
     use crate::nvme::types::SMART_LOG_BYTES;
 
     #[test]
@@ -352,5 +1702,456 @@ mod tests {
         let parsed = ErrorLogSummary::parse(&bytes).expect("error log should parse");
         assert_eq!(parsed.non_zero_entries, 2);
         assert_eq!(parsed.max_error_count, 5);
+        assert_eq!(parsed.entries.len(), 2);
+        assert_eq!(parsed.entries[0].error_count, 5);
+        assert_eq!(parsed.entries[1].error_count, 2);
+    }
+
+    #[test]
+    fn error_log_entry_decodes_all_fields() {
+        let mut bytes = [0_u8; ERROR_LOG_ENTRY_BYTES];
+        bytes[0..8].copy_from_slice(&42_u64.to_le_bytes());
+        bytes[8..10].copy_from_slice(&3_u16.to_le_bytes());
+        bytes[10..12].copy_from_slice(&0x1234_u16.to_le_bytes());
+        bytes[12..14].copy_from_slice(&((0x0281_u16 << 1) | 1).to_le_bytes());
+        bytes[14..16].copy_from_slice(&0x0528_u16.to_le_bytes());
+        bytes[16..24].copy_from_slice(&0xDEAD_BEEF_u64.to_le_bytes());
+        bytes[24..28].copy_from_slice(&1_u32.to_le_bytes());
+        bytes[28] = 0xC1;
+        bytes[29] = 3;
+        bytes[32..40].copy_from_slice(&7_u64.to_le_bytes());
+        bytes[40..42].copy_from_slice(&9_u16.to_le_bytes());
+
+        let entry = ErrorLogEntry::parse(&bytes).expect("error log entry should parse");
+        assert_eq!(entry.error_count, 42);
+        assert_eq!(entry.submission_queue_id, 3);
+        assert_eq!(entry.command_id, 0x1234);
+        assert!(entry.phase_tag);
+        assert_eq!(
+            entry.status.status_code_type,
+            StatusCodeType::MediaAndDataIntegrity
+        );
+        assert_eq!(entry.status.label(), "unrecovered_read_error");
+        assert_eq!(entry.parameter_error_byte, Some(0x28));
+        assert_eq!(entry.parameter_error_bit, Some(5));
+        assert_eq!(entry.lba, 0xDEAD_BEEF);
+        assert_eq!(entry.nsid, 1);
+        assert_eq!(entry.vendor_specific_log_page, 0xC1);
+        assert_eq!(entry.transport_type, 3);
+        assert_eq!(entry.command_specific, 7);
+        assert_eq!(entry.transport_specific, 9);
+    }
+
+    #[test]
+    fn error_log_entry_without_parameter_location() {
+        let mut bytes = [0_u8; ERROR_LOG_ENTRY_BYTES];
+        bytes[14..16].copy_from_slice(&0xFFFF_u16.to_le_bytes());
+        let entry = ErrorLogEntry::parse(&bytes).expect("error log entry should parse");
+        assert_eq!(entry.parameter_error_byte, None);
+        assert_eq!(entry.parameter_error_bit, None);
+    }
+
+    #[test]
+    fn identify_namespace_decodes_formatted_lba_and_identifiers() {
+        let mut bytes = [0_u8; IDENTIFY_BYTES];
+        bytes[0..8].copy_from_slice(&1_000_u64.to_le_bytes());
+        bytes[8..16].copy_from_slice(&1_000_u64.to_le_bytes());
+        bytes[16..24].copy_from_slice(&250_u64.to_le_bytes());
+        bytes[25] = 1;
+        bytes[26] = 0x11;
+        bytes[29] = 0b1001;
+        bytes[120..128].copy_from_slice(&[0x00, 0x25, 0x38, 0x5a, 0x91, 0x50, 0x1d, 0x2e]);
+        bytes[128..132].copy_from_slice(&(9_u32 << 16).to_le_bytes());
+        bytes[132..136].copy_from_slice(&((1_u32 << 24) | (12 << 16) | 8).to_le_bytes());
+
+        let parsed = IdentifyNamespace::parse(&bytes).expect("identify namespace should parse");
+        assert_eq!(parsed.lba_formats.len(), 2);
+        assert_eq!(parsed.formatted_lba_index(), 1);
+        let format = parsed
+            .formatted_lba_format()
+            .expect("formatted lba format should exist");
+        assert_eq!(format.metadata_size, 8);
+        assert_eq!(format.relative_performance_name(), "better");
+        assert!(parsed.metadata_extended_lba());
+        assert_eq!(parsed.lba_size_bytes(), Some(4096));
+        assert_eq!(parsed.size_bytes(), Some(4_096_000));
+        assert_eq!(parsed.utilization_bytes(), Some(1_024_000));
+        assert_eq!(parsed.protection_type_name(), "type1");
+        assert!(parsed.protection_info_first());
+        assert_eq!(parsed.nguid_string(), None);
+        assert_eq!(parsed.eui64_string().as_deref(), Some("0025385a91501d2e"));
+    }
+
+    #[test]
+    fn namespace_id_list_stops_at_first_zero() {
+        let mut bytes = [0_u8; IDENTIFY_BYTES];
+        bytes[0..4].copy_from_slice(&1_u32.to_le_bytes());
+        bytes[4..8].copy_from_slice(&3_u32.to_le_bytes());
+        bytes[12..16].copy_from_slice(&9_u32.to_le_bytes());
+        let parsed = NamespaceIdList::parse(&bytes).expect("namespace list should parse");
+        assert_eq!(parsed.nsids, vec![1, 3]);
+        assert!(!parsed.full());
+    }
+
+    #[test]
+    fn endurance_group_list_reads_counted_ids() {
+        let mut bytes = [0_u8; IDENTIFY_BYTES];
+        bytes[0..2].copy_from_slice(&2_u16.to_le_bytes());
+        bytes[2..4].copy_from_slice(&1_u16.to_le_bytes());
+        bytes[4..6].copy_from_slice(&7_u16.to_le_bytes());
+        bytes[6..8].copy_from_slice(&9_u16.to_le_bytes());
+        let list = EnduranceGroupList::parse(&bytes).expect("list should parse");
+        assert_eq!(list.ids, vec![1, 7]);
+        assert!(!list.full());
+    }
+
+    #[test]
+    fn endurance_group_log_decodes_wear_and_units() {
+        let mut bytes = [0_u8; ENDURANCE_GROUP_LOG_BYTES];
+        bytes[3] = 95;
+        bytes[4] = 10;
+        bytes[5] = 3;
+        bytes[32..48].copy_from_slice(&600_u128.to_le_bytes());
+        bytes[64..80].copy_from_slice(&1_234_u128.to_le_bytes());
+        bytes[80..96].copy_from_slice(&2_468_u128.to_le_bytes());
+        let parsed = EnduranceGroupLog::parse(&bytes).expect("endurance group log should parse");
+        assert!((parsed.available_spare_ratio() - 0.95).abs() < 1e-9);
+        assert!((parsed.percent_used_ratio() - 0.03).abs() < 1e-9);
+        assert_eq!(parsed.endurance_estimate_bytes(), 600_000_000_000.0);
+        assert_eq!(parsed.data_units_written, 1_234);
+        assert_eq!(parsed.media_units_written, 2_468);
+        assert!(EnduranceGroupLog::parse(&bytes[..256]).is_err());
+    }
+
+    #[test]
+    fn telemetry_header_decodes_data_areas_and_controller_flags() {
+        let mut bytes = [0_u8; 512];
+        bytes[0] = 0x07;
+        bytes[5..8].copy_from_slice(&[0xE4, 0xD2, 0x5C]);
+        bytes[8..10].copy_from_slice(&3_u16.to_le_bytes());
+        bytes[10..12].copy_from_slice(&40_u16.to_le_bytes());
+        bytes[12..14].copy_from_slice(&200_u16.to_le_bytes());
+        bytes[16..20].copy_from_slice(&70_000_u32.to_le_bytes());
+        bytes[381] = 2;
+        bytes[382] = 1;
+        bytes[383] = 9;
+        bytes[384] = 0xAB;
+
+        let parsed = TelemetryLogHeader::parse(&bytes).expect("telemetry header should parse");
+        assert_eq!(parsed.lid, 0x07);
+        assert_eq!(parsed.ieee_oui, 0x5CD2E4);
+        assert_eq!(parsed.data_area_last_blocks, [3, 40, 200, 70_000]);
+        assert_eq!(parsed.data_area_bytes(1), 4 * 512);
+        assert_eq!(parsed.data_area_bytes(3), 201 * 512);
+        assert_eq!(parsed.data_area_bytes(4), 70_001 * 512);
+        assert_eq!(parsed.host_generation_number, 2);
+        assert!(parsed.controller_data_available);
+        assert_eq!(parsed.controller_generation_number, 9);
+        assert!(parsed.reason_identifier_string().starts_with("ab00"));
+        assert!(TelemetryLogHeader::parse(&bytes[..256]).is_err());
+    }
+
+    #[test]
+    fn persistent_event_log_decodes_events() {
+        let mut bytes = vec![0_u8; 512 + 24 + 16 + 24 + 4];
+        bytes[0] = 0x0D;
+        bytes[4..8].copy_from_slice(&2_u32.to_le_bytes());
+        let total_length = bytes.len() as u64;
+        bytes[8..16].copy_from_slice(&total_length.to_le_bytes());
+        bytes[372..374].copy_from_slice(&3_u16.to_le_bytes());
+        bytes[480] = 0b0000_0100;
+        bytes[481] = 0b0010_0000;
+
+        let first = 512;
+        bytes[first] = 0x02;
+        bytes[first + 2] = 21;
+        bytes[first + 4..first + 6].copy_from_slice(&1_u16.to_le_bytes());
+        bytes[first + 6..first + 14].copy_from_slice(&(0x0001_0000_0000_1000_u64).to_le_bytes());
+        bytes[first + 22..first + 24].copy_from_slice(&16_u16.to_le_bytes());
+        bytes[first + 24..first + 32].copy_from_slice(b"FW100   ");
+        bytes[first + 32..first + 40].copy_from_slice(b"FW200   ");
+
+        let second = first + 24 + 16;
+        bytes[second] = 0x0D;
+        bytes[second + 2] = 21;
+        bytes[second + 20..second + 22].copy_from_slice(&2_u16.to_le_bytes());
+        bytes[second + 22..second + 24].copy_from_slice(&4_u16.to_le_bytes());
+        bytes[second + 26] = 5;
+        bytes[second + 27] = 80;
+
+        let parsed = PersistentEventLog::parse(&bytes).expect("persistent event log should parse");
+        assert_eq!(parsed.header.total_events, 2);
+        assert_eq!(parsed.header.generation_number, 3);
+        assert!(parsed.header.supports_event(0x02));
+        assert!(parsed.header.supports_event(0x0D));
+        assert!(!parsed.header.supports_event(0x01));
+        assert_eq!(parsed.events.len(), 2);
+        assert_eq!(parsed.events[0].type_name(), "firmware_commit");
+        assert_eq!(parsed.events[0].controller_id, 1);
+        assert_eq!(parsed.events[0].timestamp_ms, 0x1000);
+        assert_eq!(
+            parsed.events[0].detail(),
+            "old_firmware=FW100 new_firmware=FW200"
+        );
+        assert_eq!(parsed.events[1].type_name(), "thermal_excursion");
+        assert_eq!(
+            parsed.events[1].detail(),
+            "over_temperature_celsius=5 threshold_celsius=80"
+        );
+
+        bytes[second + 22..second + 24].copy_from_slice(&40_u16.to_le_bytes());
+        assert!(PersistentEventLog::parse(&bytes).is_err());
+        bytes[0] = 0x02;
+        assert!(PersistentEventLog::parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn namespace_descriptors_decode_uuid_and_csi() {
+        let mut bytes = [0_u8; IDENTIFY_BYTES];
+        bytes[0] = 0x01;
+        bytes[1] = 8;
+        bytes[4..12].copy_from_slice(&[0x00, 0x25, 0x38, 0x5a, 0x91, 0x50, 0x1d, 0x2e]);
+        bytes[12] = 0x03;
+        bytes[13] = 16;
+        bytes[16..32].copy_from_slice(&[
+            0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab,
+            0xcd, 0xef,
+        ]);
+        bytes[32] = 0x04;
+        bytes[33] = 1;
+        bytes[36] = 0x02;
+
+        let parsed = NamespaceIdentificationDescriptors::parse(&bytes)
+            .expect("namespace descriptors should parse");
+        assert_eq!(parsed.eui64_string().as_deref(), Some("0025385a91501d2e"));
+        assert_eq!(parsed.nguid_string(), None);
+        assert_eq!(
+            parsed.uuid_string().as_deref(),
+            Some("12345678-9abc-def0-0123-456789abcdef")
+        );
+        assert_eq!(parsed.csi_name().as_deref(), Some("zoned"));
+    }
+
+    #[test]
+    fn zoned_namespace_decodes_zone_size_and_resources() {
+        let mut namespace_bytes = [0_u8; IDENTIFY_BYTES];
+        namespace_bytes[0..8].copy_from_slice(&(1_u64 << 21).to_le_bytes());
+        namespace_bytes[25] = 1;
+        namespace_bytes[26] = 1;
+        namespace_bytes[128..132].copy_from_slice(&(9_u32 << 16).to_le_bytes());
+        namespace_bytes[132..136].copy_from_slice(&(12_u32 << 16).to_le_bytes());
+        let namespace =
+            IdentifyNamespace::parse(&namespace_bytes).expect("identify namespace should parse");
+
+        let mut bytes = [0_u8; IDENTIFY_BYTES];
+        bytes[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+        bytes[8..12].copy_from_slice(&13_u32.to_le_bytes());
+        bytes[2832..2840].copy_from_slice(&(1_u64 << 14).to_le_bytes());
+        bytes[2840] = 2;
+        let parsed = ZonedNamespace::parse(&bytes).expect("zoned namespace should parse");
+        assert_eq!(parsed.max_active_resources, None);
+        assert_eq!(parsed.max_open_resources, Some(14));
+        assert_eq!(parsed.zone_formats[1].descriptor_extension_bytes, 128);
+        assert_eq!(parsed.zone_size_bytes(&namespace), Some(64 * 1024 * 1024));
+        assert_eq!(parsed.zone_count(&namespace), Some(128));
+
+        let mut report = [0_u8; 64];
+        report[0..8].copy_from_slice(&42_u64.to_le_bytes());
+        let header = ZoneReportHeader::parse(&report).expect("zone report header should parse");
+        assert_eq!(header.number_of_zones, 42);
+    }
+
+    #[test]
+    fn identify_controller_decodes_capabilities_and_capacity() {
+        let mut bytes = [0_u8; IDENTIFY_BYTES];
+        bytes[0..2].copy_from_slice(&0x8086_u16.to_le_bytes());
+        bytes[73..76].copy_from_slice(&[0xE4, 0xD2, 0x5C]);
+        bytes[77] = 5;
+        bytes[80..84].copy_from_slice(&0x0002_0000_u32.to_le_bytes());
+        bytes[96..100].copy_from_slice(&(1_u32 << 4).to_le_bytes());
+        bytes[340..342].copy_from_slice(&4_u16.to_le_bytes());
+        bytes[256..258].copy_from_slice(&0x0016_u16.to_le_bytes());
+        bytes[261] = 0b0001_1001;
+        bytes[266..268].copy_from_slice(&343_u16.to_le_bytes());
+        bytes[280..296].copy_from_slice(&4_000_787_030_016_u128.to_le_bytes());
+        bytes[516..520].copy_from_slice(&32_u32.to_le_bytes());
+        bytes[768..780].copy_from_slice(b"nqn.2014.08.");
+        bytes[263] = 1;
+        bytes[2048..2050].copy_from_slice(&900_u16.to_le_bytes());
+        bytes[2052..2056].copy_from_slice(&5_u32.to_le_bytes());
+        bytes[2080..2082].copy_from_slice(&40_000_u16.to_le_bytes());
+        bytes[2083] = 0b11;
+        bytes[2084..2088].copy_from_slice(&2000_u32.to_le_bytes());
+        bytes[2088..2092].copy_from_slice(&8000_u32.to_le_bytes());
+        bytes[2096..2098].copy_from_slice(&30_u16.to_le_bytes());
+        bytes[2098] = 0b10 << 6;
+
+        let mut parsed = IdentifyController::parse(&bytes).expect("identify should parse");
+        assert_eq!(parsed.vendor_id, 0x8086);
+        assert_eq!(parsed.ieee_oui, 0x5CD2E4);
+        assert_eq!(parsed.max_data_transfer_bytes(), Some(128 * 1024));
+        parsed.mdts = 0;
+        assert_eq!(parsed.max_data_transfer_bytes(), None);
+        parsed.mdts = 64;
+        assert_eq!(parsed.max_data_transfer_bytes(), Some(1024 * 1024));
+        assert_eq!(parsed.version_string(), "2.0.0");
+        assert!(parsed.version_at_least(1, 3));
+        assert!(!parsed.version_at_least(2, 1));
+        assert!(parsed.supports_endurance_groups());
+        assert_eq!(parsed.endgidmax, 4);
+        assert!(parsed.supports_self_test());
+        assert!(parsed.supports_firmware_download());
+        assert!(!parsed.supports_namespace_management());
+        assert!(parsed.supports_per_namespace_smart());
+        assert!(parsed.supports_telemetry());
+        assert!(parsed.supports_persistent_event_log());
+        assert!(!parsed.supports_extended_log_page());
+        assert_eq!(parsed.tnvmcap, 4_000_787_030_016);
+        assert_eq!(parsed.number_of_namespaces, 32);
+        assert_eq!(parsed.subsystem_nqn, "nqn.2014.08.");
+        let warning = parsed
+            .warning_temperature_celsius()
+            .expect("warning threshold should be set");
+        assert!((warning - 69.85).abs() < 1e-9);
+        assert_eq!(parsed.critical_temperature_celsius(), None);
+
+        assert_eq!(parsed.power_states.len(), 2);
+        assert!((parsed.power_states[0].max_power_watts - 9.0).abs() < 1e-9);
+        assert!(!parsed.power_states[0].non_operational);
+        assert_eq!(parsed.power_states[0].entry_latency_us, 5);
+        assert_eq!(parsed.power_states[0].idle_power_watts, None);
+        assert!((parsed.power_states[1].max_power_watts - 4.0).abs() < 1e-9);
+        assert!(parsed.power_states[1].non_operational);
+        assert_eq!(parsed.power_states[1].exit_latency_us, 8000);
+        let idle = parsed.power_states[1]
+            .idle_power_watts
+            .expect("idle power should be reported");
+        assert!((idle - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn feature_dwords_decode() {
+        let arbitration = Arbitration::parse(0x0302_0103);
+        assert_eq!(arbitration.burst, Some(8));
+        assert_eq!(arbitration.low_priority_weight, 1);
+        assert_eq!(arbitration.medium_priority_weight, 2);
+        assert_eq!(arbitration.high_priority_weight, 3);
+        assert_eq!(Arbitration::parse(0x07).burst, None);
+
+        let queues = NumberOfQueues::parse(0x001F_003F);
+        assert_eq!(queues.submission_queues, 64);
+        assert_eq!(queues.completion_queues, 32);
+
+        assert_eq!(TemperatureThreshold::select(3, true), 0x0013_0000);
+        assert_eq!(
+            TemperatureThreshold::parse(0x0000_0157).threshold_kelvin,
+            343
+        );
+    }
+
+    #[test]
+    fn apst_table_decodes() {
+        let mut bytes = [0_u8; APST_DATA_BYTES];
+        bytes[0..8].copy_from_slice(&((100_u64 << 8) | (3 << 3)).to_le_bytes());
+        bytes[8..16].copy_from_slice(&((2000_u64 << 8) | (4 << 3)).to_le_bytes());
+
+        let parsed = AutonomousPowerStateTransition::parse(1, &bytes).expect("apst should parse");
+        assert!(parsed.enabled);
+        assert_eq!(parsed.entries.len(), 32);
+        assert_eq!(parsed.entries[0].idle_transition_power_state, 3);
+        assert_eq!(parsed.entries[0].idle_time_prior_to_transition_ms, 100);
+        assert_eq!(parsed.entries[1].idle_transition_power_state, 4);
+        assert_eq!(parsed.entries[1].idle_time_prior_to_transition_ms, 2000);
+        assert_eq!(parsed.entries[2].idle_time_prior_to_transition_ms, 0);
+    }
+
+    #[test]
+    fn host_memory_buffer_decodes_size() {
+        let mut bytes = [0_u8; HOST_MEMORY_BUFFER_DATA_BYTES];
+        bytes[0..4].copy_from_slice(&16_384_u32.to_le_bytes());
+        bytes[12..16].copy_from_slice(&8_u32.to_le_bytes());
+        let parsed = HostMemoryBuffer::parse(0x1, &bytes).expect("hmb should parse");
+        assert!(parsed.enabled);
+        assert!(!parsed.memory_return);
+        assert_eq!(parsed.size_bytes(), 64 * 1024 * 1024);
+        assert_eq!(parsed.descriptor_entries, 8);
+    }
+
+    #[test]
+    fn timestamp_decodes_origin_and_synch() {
+        let mut bytes = [0_u8; 8];
+        bytes[0..6].copy_from_slice(&1_700_000_000_000_u64.to_le_bytes()[0..6]);
+        bytes[6] = (1 << 1) | 1;
+        let parsed = Timestamp::parse(&bytes).expect("timestamp should parse");
+        assert_eq!(parsed.milliseconds, 1_700_000_000_000);
+        assert!(parsed.synch);
+        assert_eq!(parsed.origin, 1);
+    }
+
+    #[test]
+    fn firmware_slot_log_decodes_active_and_pending_slots() {
+        let mut bytes = [0_u8; FIRMWARE_SLOT_LOG_BYTES];
+        bytes[0] = (2 << 4) | 1;
+        bytes[8..16].copy_from_slice(b"1.0.0   ");
+        bytes[16..24].copy_from_slice(b"1.1.0\0\0\0");
+
+        let parsed = FirmwareSlotLog::parse(&bytes).expect("firmware slot log should parse");
+        assert_eq!(parsed.active_slot, 1);
+        assert_eq!(parsed.next_reset_slot, Some(2));
+        assert!(parsed.pending_activation());
+        assert_eq!(parsed.slots.len(), 2);
+        assert_eq!(parsed.slots[0].slot, 1);
+        assert_eq!(parsed.slots[0].revision, "1.0.0");
+        assert_eq!(parsed.slots[1].slot, 2);
+        assert_eq!(parsed.slots[1].revision, "1.1.0");
+    }
+
+    #[test]
+    fn self_test_log_decodes_result_history() {
+        let mut bytes = [0_u8; SELF_TEST_LOG_BYTES];
+        bytes[0] = SELF_TEST_OPERATION_EXTENDED;
+        bytes[1] = 40;
+        for index in 0..20 {
+            bytes[4 + (index * 28)] = 0x0F;
+        }
+
+        bytes[4] = (SELF_TEST_OPERATION_EXTENDED << 4) | 0x7;
+        bytes[5] = 3;
+        bytes[6] = 0b0000_1111;
+        bytes[8..16].copy_from_slice(&1200_u64.to_le_bytes());
+        bytes[16..20].copy_from_slice(&1_u32.to_le_bytes());
+        bytes[20..28].copy_from_slice(&0x1000_u64.to_le_bytes());
+        bytes[28] = 0x2;
+        bytes[29] = 0x81;
+
+        bytes[32] = SELF_TEST_OPERATION_SHORT << 4;
+        bytes[36..44].copy_from_slice(&1100_u64.to_le_bytes());
+
+        bytes[60] = SELF_TEST_OPERATION_SHORT << 4;
+        bytes[64..72].copy_from_slice(&1000_u64.to_le_bytes());
+
+        let parsed = SelfTestLogSummary::parse(&bytes).expect("self-test log should parse");
+        assert_eq!(parsed.current_operation, SELF_TEST_OPERATION_EXTENDED);
+        assert!((parsed.current_completion_ratio - 0.4).abs() < f64::EPSILON);
+        assert_eq!(parsed.results.len(), 3);
+
+        let extended = parsed
+            .last_result(SELF_TEST_OPERATION_EXTENDED)
+            .expect("extended result should exist");
+        assert!(extended.failed());
+        assert_eq!(extended.result_name(), "failed_segment");
+        assert_eq!(extended.segment_number, 3);
+        assert_eq!(extended.power_on_hours, 1200);
+        assert_eq!(extended.nsid, Some(1));
+        assert_eq!(extended.failing_lba, Some(0x1000));
+        assert_eq!(extended.status_code_type, Some(0x2));
+        assert_eq!(extended.status_code, Some(0x81));
+
+        let short = parsed
+            .last_result(SELF_TEST_OPERATION_SHORT)
+            .expect("short result should exist");
+        assert!(!short.failed());
+        assert_eq!(short.power_on_hours, 1100);
+        assert_eq!(short.nsid, None);
     }
 }
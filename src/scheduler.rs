@@ -0,0 +1,373 @@
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use tracing::info;
+use tracing::warn;
+
+use crate::collector::NvmeCollector;
+use crate::nvme::device::NvmeDevice;
+use crate::nvme::discovery;
+use crate::nvme::discovery::NvmeController;
+use crate::nvme::error::NvmeError;
+use crate::nvme::types::self_test_operation_name;
+use crate::nvme::types::SELF_TEST_OPERATION_EXTENDED;
+use crate::nvme::types::SELF_TEST_OPERATION_SHORT;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UtcMinute {
+    pub minute: u32,
+    pub hour: u32,
+    pub day_of_month: u32,
+    pub month: u32,
+    pub day_of_week: u32,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self, NvmeError> {
+        let trimmed = expression.trim();
+        let expanded = match trimmed {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(NvmeError::Parse(format!(
+                "schedule '{}' must have 5 fields (minute hour day-of-month month day-of-week)",
+                expression
+            )));
+        }
+
+        let mut days_of_week = parse_field(expression, fields[4], 0, 7)?;
+        if (days_of_week & (1 << 7)) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            expression: trimmed.to_string(),
+            minutes: parse_field(expression, fields[0], 0, 59)?,
+            hours: parse_field(expression, fields[1], 0, 23)?,
+            days_of_month: parse_field(expression, fields[2], 1, 31)?,
+            months: parse_field(expression, fields[3], 1, 12)?,
+            days_of_week,
+            day_of_month_restricted: fields[2] != "*",
+            day_of_week_restricted: fields[4] != "*",
+        })
+    }
+
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    pub fn matches(&self, time: &UtcMinute) -> bool {
+        let day_of_month = bit_set(self.days_of_month, time.day_of_month);
+        let day_of_week = bit_set(self.days_of_week, time.day_of_week);
+        let day = if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        };
+
+        day && bit_set(self.minutes, time.minute)
+            && bit_set(self.hours, time.hour)
+            && bit_set(self.months, time.month)
+    }
+}
+
+impl UtcMinute {
+    pub fn from_unix_seconds(seconds: u64) -> Self {
+        let days = seconds / 86_400;
+        let seconds_of_day = seconds % 86_400;
+        let (_, month, day_of_month) = civil_from_days(days);
+
+        Self {
+            minute: ((seconds_of_day / 60) % 60) as u32,
+            hour: (seconds_of_day / 3600) as u32,
+            day_of_month,
+            month,
+            day_of_week: ((days + 4) % 7) as u32,
+        }
+    }
+}
+
+pub async fn run_self_test_scheduler(
+    collector: Arc<NvmeCollector>,
+    device_pattern: String,
+    short: Option<CronSchedule>,
+    extended: Option<CronSchedule>,
+    timeout_ms: u32,
+) {
+    let mut last_minute = None;
+
+    loop {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let minute_index = now.as_secs() / 60;
+        if last_minute != Some(minute_index) {
+            last_minute = Some(minute_index);
+            let time = UtcMinute::from_unix_seconds(now.as_secs());
+
+            let due = if extended.as_ref().is_some_and(|value| value.matches(&time)) {
+                Some(SELF_TEST_OPERATION_EXTENDED)
+            } else if short.as_ref().is_some_and(|value| value.matches(&time)) {
+                Some(SELF_TEST_OPERATION_SHORT)
+            } else {
+                None
+            };
+
+            if let Some(operation) = due {
+                let collector = collector.clone();
+                let device_pattern = device_pattern.clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    run_scheduled_self_tests(&collector, &device_pattern, operation, timeout_ms)
+                })
+                .await;
+                match result {
+                    Ok(Ok(())) => {}
+                    Ok(Err(error)) => warn!(error = %error, "scheduled self-test run failed"),
+                    Err(error) => warn!(error = %error, "scheduled self-test task join failure"),
+                }
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let elapsed_in_minute = Duration::from_millis((now.as_millis() % 60_000) as u64);
+        let sleep_for = Duration::from_secs(60).saturating_sub(elapsed_in_minute);
+        tokio::time::sleep(sleep_for.max(Duration::from_secs(1))).await;
+    }
+}
+
+fn run_scheduled_self_tests(
+    collector: &NvmeCollector,
+    device_pattern: &str,
+    operation: u8,
+    timeout_ms: u32,
+) -> Result<(), NvmeError> {
+    let test_type = self_test_operation_name(operation);
+    let controllers = discovery::discover_controllers(device_pattern)?;
+
+    for controller in &controllers {
+        collector.record_self_test_outcome(&controller.name, &test_type, "scheduled");
+        let outcome = match start_self_test(controller, operation, timeout_ms) {
+            Ok(SelfTestStart::Started) => {
+                info!(
+                    controller = %controller.name,
+                    test_type = %test_type,
+                    "started scheduled self-test"
+                );
+                "started"
+            }
+            Ok(SelfTestStart::InProgress) => {
+                info!(
+                    controller = %controller.name,
+                    test_type = %test_type,
+                    "self-test already in progress, skipping scheduled run"
+                );
+                "skipped"
+            }
+            Ok(SelfTestStart::Unsupported) => {
+                info!(
+                    controller = %controller.name,
+                    test_type = %test_type,
+                    "controller does not support device self-test, skipping scheduled run"
+                );
+                "unsupported"
+            }
+            Err(error) => {
+                collector.record_command_error(controller, &error);
+                warn!(
+                    controller = %controller.name,
+                    test_type = %test_type,
+                    error = %error,
+                    "failed to start scheduled self-test"
+                );
+                "failed"
+            }
+        };
+        collector.record_self_test_outcome(&controller.name, &test_type, outcome);
+    }
+
+    Ok(())
+}
+
+enum SelfTestStart {
+    Started,
+    InProgress,
+    Unsupported,
+}
+
+fn start_self_test(
+    controller: &NvmeController,
+    operation: u8,
+    timeout_ms: u32,
+) -> Result<SelfTestStart, NvmeError> {
+    let device = NvmeDevice::open(&controller.dev_path)?;
+    let identify = device.identify_controller(timeout_ms)?;
+    if !identify.supports_self_test() {
+        return Ok(SelfTestStart::Unsupported);
+    }
+
+    let log = device.self_test_log(timeout_ms)?;
+    if log.current_operation != 0 {
+        return Ok(SelfTestStart::InProgress);
+    }
+
+    device.device_self_test(operation, timeout_ms)?;
+    Ok(SelfTestStart::Started)
+}
+
+fn parse_field(expression: &str, field: &str, min: u32, max: u32) -> Result<u64, NvmeError> {
+    let invalid = || {
+        NvmeError::Parse(format!(
+            "invalid schedule field '{}' in '{}' (allowed range {}-{})",
+            field, expression, min, max
+        ))
+    };
+
+    let mut mask = 0_u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step.parse::<u32>().map_err(|_| invalid())?;
+                if step == 0 {
+                    return Err(invalid());
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                start.parse::<u32>().map_err(|_| invalid())?,
+                end.parse::<u32>().map_err(|_| invalid())?,
+            )
+        } else {
+            let value = range.parse::<u32>().map_err(|_| invalid())?;
+            if part.contains('/') {
+                (value, max)
+            } else {
+                (value, value)
+            }
+        };
+
+        if start < min || end > max || start > end {
+            return Err(invalid());
+        }
+
+        let mut value = start;
+        while value <= end {
+            mask |= 1 << value;
+            value += step;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn bit_set(mask: u64, value: u32) -> bool {
+    value < 64 && (mask & (1 << value)) != 0
+}
+
+fn civil_from_days(days: u64) -> (i64, u32, u32) {
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scheduler::CronSchedule;
+    use crate::scheduler::UtcMinute;
+
+    #[test]
+    fn converts_unix_seconds_to_utc_fields() {
+        let time = UtcMinute::from_unix_seconds(1_767_323_700);
+        assert_eq!(
+            time,
+            UtcMinute {
+                minute: 15,
+                hour: 3,
+                day_of_month: 2,
+                month: 1,
+                day_of_week: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn daily_and_weekly_schedules_match() {
+        let daily = CronSchedule::parse("0 2 * * *").expect("daily schedule should parse");
+        let weekly = CronSchedule::parse("30 3 * * 7").expect("weekly schedule should parse");
+        let sunday = UtcMinute {
+            minute: 30,
+            hour: 3,
+            day_of_month: 4,
+            month: 1,
+            day_of_week: 0,
+        };
+
+        assert!(weekly.matches(&sunday));
+        assert!(!daily.matches(&sunday));
+        assert!(daily.matches(&UtcMinute {
+            minute: 0,
+            hour: 2,
+            ..sunday
+        }));
+    }
+
+    #[test]
+    fn supports_steps_ranges_and_lists() {
+        let schedule = CronSchedule::parse("*/15 1-5/2 * * 1,3").expect("schedule should parse");
+        let base = UtcMinute {
+            minute: 45,
+            hour: 3,
+            day_of_month: 1,
+            month: 6,
+            day_of_week: 3,
+        };
+        assert!(schedule.matches(&base));
+        assert!(!schedule.matches(&UtcMinute { hour: 2, ..base }));
+        assert!(!schedule.matches(&UtcMinute {
+            day_of_week: 2,
+            ..base
+        }));
+    }
+
+    #[test]
+    fn rejects_invalid_schedules() {
+        assert!(CronSchedule::parse("0 2 * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("@weekly").is_ok());
+    }
+}
@@ -1,39 +1,85 @@
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::Mutex;
 
+use axum::extract::Path;
 use axum::extract::State;
 use axum::http::header::CONTENT_TYPE;
 use axum::http::StatusCode;
 use axum::response::Html;
 use axum::response::IntoResponse;
 use axum::routing::get;
+use axum::routing::post;
 use axum::Router;
 use tokio::net::TcpListener;
 use tracing::error;
+use tracing::info;
 
 use crate::collector::NvmeCollector;
 use crate::config::Config;
 use crate::nvme::error::NvmeError;
+use crate::telemetry;
 
 #[derive(Clone)]
 struct AppState {
     collector: Arc<NvmeCollector>,
+    devices: String,
+    telemetry_dir: PathBuf,
+    telemetry_max_bytes: u64,
+    timeout_ms: u32,
+    capture_lock: Arc<Mutex<()>>,
 }
 
 pub async fn run_server(config: &Config, collector: Arc<NvmeCollector>) -> Result<(), NvmeError> {
+    let timeout_ms = u32::try_from(config.ioctl_timeout.as_millis())
+        .map_err(|_| NvmeError::Parse("ioctl timeout exceeds u32".to_string()))?;
+
+    let state = AppState {
+        collector,
+        devices: config.devices.clone(),
+        telemetry_dir: config.telemetry_dir.clone(),
+        telemetry_max_bytes: config.telemetry_max_bytes,
+        timeout_ms,
+        capture_lock: Arc::new(Mutex::new(())),
+    };
     let app = Router::new()
         .route("/", get(root_handler))
         .route("/health", get(health_handler))
         .route("/metrics", get(metrics_handler))
-        .with_state(AppState { collector });
+        .with_state(state.clone());
 
     let listener = TcpListener::bind(config.listen_address)
         .await
         .map_err(|source| NvmeError::io_context("bind listen socket", source))?;
+    let server = async {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(shutdown_signal())
+            .await
+            .map_err(|source| NvmeError::io_context("http server", source))
+    };
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
+    if !config.admin_endpoints {
+        return server.await;
+    }
+
+    let admin_app = Router::new()
+        .route("/admin/telemetry/{device}", post(telemetry_handler))
+        .with_state(state);
+    let admin_listener = TcpListener::bind(config.admin_listen_address)
         .await
-        .map_err(|source| NvmeError::io_context("http server", source))
+        .map_err(|source| NvmeError::io_context("bind admin listen socket", source))?;
+    info!(
+        admin_listen_address = %config.admin_listen_address,
+        "admin endpoints enabled"
+    );
+    let admin_server = async {
+        axum::serve(admin_listener, admin_app)
+            .with_graceful_shutdown(shutdown_signal())
+            .await
+            .map_err(|source| NvmeError::io_context("admin http server", source))
+    };
+
+    tokio::try_join!(server, admin_server).map(|_| ())
 }
 
 async fn root_handler() -> impl IntoResponse {
@@ -75,6 +121,57 @@ async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
     }
 }
 
+async fn telemetry_handler(
+    State(state): State<AppState>,
+    Path(device): Path<String>,
+) -> impl IntoResponse {
+    let result = tokio::task::spawn_blocking(move || {
+        let _guard = state
+            .capture_lock
+            .lock()
+            .map_err(|error| NvmeError::Internal(format!("capture mutex poisoned: {}", error)))?;
+        match telemetry::find_controller(&state.devices, &device)? {
+            Some(controller) => telemetry::capture_host_telemetry(
+                &controller,
+                &state.telemetry_dir,
+                state.telemetry_max_bytes,
+                state.timeout_ms,
+            )
+            .map(Some),
+            None => Ok(None),
+        }
+    })
+    .await;
+
+    match result {
+        Ok(Ok(Some(capture))) => format!(
+            "device={}\nbytes={}\nblob={}\nmetadata={}\n",
+            capture.device,
+            capture.bytes,
+            capture.blob_path.display(),
+            capture.metadata_path.display()
+        )
+        .into_response(),
+        Ok(Ok(None)) => (StatusCode::NOT_FOUND, "unknown device".to_string()).into_response(),
+        Ok(Err(error)) => {
+            error!(error = %error, "telemetry capture failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("telemetry capture failed: {}", error),
+            )
+                .into_response()
+        }
+        Err(error) => {
+            error!(error = %error, "telemetry capture task join failure");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "telemetry capture task failed".to_string(),
+            )
+                .into_response()
+        }
+    }
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async {
         let _ = tokio::signal::ctrl_c().await;
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::ErrorKind;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+use tracing::info;
+
+use crate::nvme::device::NvmeDevice;
+use crate::nvme::discovery;
+use crate::nvme::discovery::NvmeController;
+use crate::nvme::error::NvmeError;
+
+#[derive(Clone, Debug)]
+pub struct TelemetryCapture {
+    pub device: String,
+    pub blob_path: PathBuf,
+    pub metadata_path: PathBuf,
+    pub bytes: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct TelemetryMetadata {
+    device: String,
+    dev_path: String,
+    model: String,
+    serial: String,
+    firmware: String,
+    vendor_id: String,
+    ieee_oui: String,
+    captured_at_unix_seconds: u64,
+    bytes: u64,
+    data_areas: usize,
+    data_area_last_blocks: Vec<u32>,
+    host_generation_number: u8,
+    controller_data_available: bool,
+    controller_generation_number: u8,
+    reason_identifier: String,
+}
+
+pub fn find_controller(
+    device_pattern: &str,
+    device: &str,
+) -> Result<Option<NvmeController>, NvmeError> {
+    let controllers = discovery::discover_controllers(device_pattern)?;
+    Ok(controllers
+        .into_iter()
+        .find(|controller| controller.name == device || controller.dev_path == Path::new(device)))
+}
+
+pub fn capture_host_telemetry(
+    controller: &NvmeController,
+    directory: &Path,
+    max_bytes: u64,
+    timeout_ms: u32,
+) -> Result<TelemetryCapture, NvmeError> {
+    let mut device = NvmeDevice::open(&controller.dev_path)?;
+    let identify = device.identify_controller(timeout_ms)?;
+    if !identify.supports_telemetry() {
+        return Err(NvmeError::InvalidData(format!(
+            "{} does not support the telemetry log pages",
+            controller.name
+        )));
+    }
+    device.set_max_transfer(&identify);
+
+    let data_areas = if identify.supports_telemetry_data_area_4() {
+        4
+    } else {
+        3
+    };
+    let log = device.host_telemetry_log(data_areas, timeout_ms)?;
+
+    let captured_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|error| {
+            NvmeError::Internal(format!("system clock before unix epoch: {}", error))
+        })?;
+    let stem = format!(
+        "{}-{}-{}-{:09}",
+        controller.name,
+        file_name_component(&identify.serial),
+        captured_at.as_secs(),
+        captured_at.subsec_nanos()
+    );
+    let blob_path = directory.join(format!("{}.bin", stem));
+    let metadata_path = directory.join(format!("{}.toml", stem));
+
+    let metadata = TelemetryMetadata {
+        device: controller.name.clone(),
+        dev_path: controller.dev_path.display().to_string(),
+        model: identify.model.clone(),
+        serial: identify.serial.clone(),
+        firmware: identify.firmware_revision.clone(),
+        vendor_id: format!("0x{:04x}", identify.vendor_id),
+        ieee_oui: format!("{:06x}", log.header.ieee_oui),
+        captured_at_unix_seconds: captured_at.as_secs(),
+        bytes: log.data.len() as u64,
+        data_areas,
+        data_area_last_blocks: log.header.data_area_last_blocks[..data_areas].to_vec(),
+        host_generation_number: log.header.host_generation_number,
+        controller_data_available: log.header.controller_data_available,
+        controller_generation_number: log.header.controller_generation_number,
+        reason_identifier: log.header.reason_identifier_string(),
+    };
+    let metadata = toml::to_string(&metadata).map_err(|error| {
+        NvmeError::Internal(format!("failed to encode telemetry metadata: {}", error))
+    })?;
+
+    fs::create_dir_all(directory).map_err(|source| NvmeError::io_path(directory, source))?;
+    write_new_file(&blob_path, &log.data)?;
+    write_new_file(&metadata_path, metadata.as_bytes())?;
+    prune_captures(directory, max_bytes, &stem)?;
+
+    info!(
+        controller = %controller.name,
+        bytes = log.data.len(),
+        generation = log.header.host_generation_number,
+        path = %blob_path.display(),
+        "captured host-initiated telemetry"
+    );
+
+    Ok(TelemetryCapture {
+        device: controller.name.clone(),
+        blob_path,
+        metadata_path,
+        bytes: log.data.len(),
+    })
+}
+
+fn write_new_file(path: &Path, contents: &[u8]) -> Result<(), NvmeError> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|source| NvmeError::io_path(path, source))?;
+    file.write_all(contents)
+        .map_err(|source| NvmeError::io_path(path, source))
+}
+
+fn prune_captures(directory: &Path, max_bytes: u64, keep: &str) -> Result<(), NvmeError> {
+    let mut captures = BTreeMap::<String, (SystemTime, u64)>::new();
+    let entries =
+        fs::read_dir(directory).map_err(|source| NvmeError::io_path(directory, source))?;
+    for entry in entries {
+        let entry = entry.map_err(|source| NvmeError::io_path(directory, source))?;
+        let path = entry.path();
+        let is_capture = path
+            .extension()
+            .is_some_and(|value| value == "bin" || value == "toml");
+        let Some(stem) = path
+            .file_stem()
+            .and_then(|value| value.to_str())
+            .filter(|value| is_capture_stem(value))
+        else {
+            continue;
+        };
+        let metadata = entry
+            .metadata()
+            .map_err(|source| NvmeError::io_path(&path, source))?;
+        if !is_capture || !metadata.is_file() {
+            continue;
+        }
+        let modified = metadata.modified().unwrap_or(UNIX_EPOCH);
+        let capture = captures.entry(stem.to_string()).or_insert((UNIX_EPOCH, 0));
+        capture.0 = capture.0.max(modified);
+        capture.1 += metadata.len();
+    }
+
+    let mut total: u64 = captures.values().map(|(_, bytes)| bytes).sum();
+    let mut oldest: Vec<(SystemTime, String, u64)> = captures
+        .into_iter()
+        .map(|(stem, (modified, bytes))| (modified, stem, bytes))
+        .collect();
+    oldest.sort();
+
+    for (_, stem, bytes) in oldest {
+        if total <= max_bytes {
+            break;
+        }
+        if stem == keep {
+            continue;
+        }
+        for extension in ["bin", "toml"] {
+            let path = directory.join(format!("{}.{}", stem, extension));
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(source) if source.kind() == ErrorKind::NotFound => {}
+                Err(source) => return Err(NvmeError::io_path(&path, source)),
+            }
+        }
+        total = total.saturating_sub(bytes);
+        info!(
+            capture = %stem,
+            bytes,
+            "removed old telemetry capture to stay within the size limit"
+        );
+    }
+    Ok(())
+}
+
+fn is_capture_stem(stem: &str) -> bool {
+    let mut parts = stem.rsplitn(3, '-');
+    let (Some(nanos), Some(secs), Some(prefix)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    let Some((controller, serial)) = prefix.split_once('-') else {
+        return false;
+    };
+    nanos.len() == 9
+        && nanos.chars().all(|ch| ch.is_ascii_digit())
+        && !secs.is_empty()
+        && secs.chars().all(|ch| ch.is_ascii_digit())
+        && discovery::is_controller_name(controller)
+        && !serial.is_empty()
+}
+
+fn file_name_component(value: &str) -> String {
+    let component: String = value
+        .trim()
+        .chars()
+        .map(|value| {
+            if value.is_ascii_alphanumeric() || value == '-' || value == '_' {
+                value
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if component.is_empty() {
+        "unknown".to_string()
+    } else {
+        component
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    use crate::telemetry::is_capture_stem;
+    use crate::telemetry::prune_captures;
+
+    #[test]
+    fn prunes_oldest_captures_over_the_size_limit() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be after the epoch")
+            .as_nanos();
+        let directory = std::env::temp_dir().join(format!(
+            "nvme-exporter-telemetry-{}-{}",
+            std::process::id(),
+            nanos
+        ));
+        fs::create_dir_all(&directory).expect("temp dir should be created");
+        for stem in [
+            "nvme0-S1-1-000000000",
+            "nvme0-S1-2-000000000",
+            "nvme0-S1-3-000000000",
+        ] {
+            fs::write(directory.join(format!("{}.bin", stem)), [0_u8; 100])
+                .expect("blob should be written");
+            fs::write(directory.join(format!("{}.toml", stem)), [0_u8; 10])
+                .expect("metadata should be written");
+        }
+        fs::write(directory.join("notes.txt"), [0_u8; 1000]).expect("notes should be written");
+        fs::write(directory.join("config.toml"), [0_u8; 1000]).expect("config should be written");
+        fs::write(directory.join("firmware.bin"), [0_u8; 1000])
+            .expect("firmware should be written");
+
+        prune_captures(&directory, 250, "nvme0-S1-1-000000000").expect("prune should succeed");
+
+        assert!(directory.join("nvme0-S1-1-000000000.bin").exists());
+        assert!(!directory.join("nvme0-S1-2-000000000.bin").exists());
+        assert!(!directory.join("nvme0-S1-2-000000000.toml").exists());
+        assert!(directory.join("nvme0-S1-3-000000000.bin").exists());
+        assert!(directory.join("notes.txt").exists());
+        assert!(directory.join("config.toml").exists());
+        assert!(directory.join("firmware.bin").exists());
+
+        fs::remove_dir_all(&directory).expect("temp dir should be removed");
+    }
+
+    #[test]
+    fn recognizes_capture_file_stems() {
+        assert!(is_capture_stem("nvme0-S1-1-000000000"));
+        assert!(is_capture_stem("nvme12-ABC-123_4-1700000000-123456789"));
+        assert!(!is_capture_stem("config"));
+        assert!(!is_capture_stem("nvme0-S1-1-0"));
+        assert!(!is_capture_stem("sda-S1-1-000000000"));
+        assert!(!is_capture_stem("nvme0-1-000000000"));
+    }
+}
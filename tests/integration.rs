@@ -1,19 +1,104 @@
+use std::collections::BTreeMap;
+
 use nvme_exporter::metrics::encode_report;
+use nvme_exporter::metrics::CommandErrorSnapshot;
 use nvme_exporter::metrics::DeviceSnapshot;
+use nvme_exporter::metrics::EnduranceGroupSnapshot;
 use nvme_exporter::metrics::ErrorLogSnapshot;
+use nvme_exporter::metrics::FeatureSnapshot;
 use nvme_exporter::metrics::NamespaceSnapshot;
+use nvme_exporter::metrics::PersistentEventLogSnapshot;
 use nvme_exporter::metrics::ScrapeReport;
 use nvme_exporter::metrics::SelfTestSnapshot;
+use nvme_exporter::metrics::TemperatureThresholdSnapshot;
+use nvme_exporter::metrics::VendorLogSnapshot;
+use nvme_exporter::metrics::ZonedNamespaceSnapshot;
+use nvme_exporter::nvme::types::Arbitration;
+use nvme_exporter::nvme::types::AutonomousPowerStateTransition;
+use nvme_exporter::nvme::types::EnduranceGroupLog;
 use nvme_exporter::nvme::types::ErrorLogSummary;
+use nvme_exporter::nvme::types::FirmwareSlotLog;
+use nvme_exporter::nvme::types::HostMemoryBuffer;
 use nvme_exporter::nvme::types::IdentifyController;
 use nvme_exporter::nvme::types::IdentifyNamespace;
+use nvme_exporter::nvme::types::NamespaceIdentificationDescriptors;
+use nvme_exporter::nvme::types::NumberOfQueues;
 use nvme_exporter::nvme::types::SelfTestLogSummary;
 use nvme_exporter::nvme::types::SmartLog;
+use nvme_exporter::nvme::types::TelemetryLogHeader;
+use nvme_exporter::nvme::types::TemperatureThreshold;
+use nvme_exporter::nvme::types::VolatileWriteCache;
+use nvme_exporter::nvme::types::ZoneState;
+use nvme_exporter::nvme::types::ZonedNamespace;
+use nvme_exporter::nvme::vendor::custom::parse_custom_log_pages;
+use nvme_exporter::nvme::vendor::custom::CustomLogSnapshot;
+use nvme_exporter::nvme::vendor::custom::CustomLogValue;
+use nvme_exporter::nvme::vendor::custom::MetricKind;
+use nvme_exporter::nvme::vendor::intel::IntelSmartLog;
+use nvme_exporter::nvme::vendor::ocp::OcpLatencyMonitorLog;
+use nvme_exporter::nvme::vendor::ocp::OcpSmartLog;
+
+fn device_snapshot(name: &str) -> DeviceSnapshot {
+    DeviceSnapshot {
+        device: name.to_string(),
+        model: "model".to_string(),
+        serial: "serial".to_string(),
+        firmware: "firmware".to_string(),
+        accessible: true,
+        controller: None,
+        smart: None,
+        namespaces: Vec::new(),
+        error_log: None,
+        self_test: None,
+        firmware_slots: None,
+        endurance_groups: Vec::new(),
+        persistent_events: None,
+        telemetry: None,
+        power_state: None,
+        features: None,
+        vendor_logs: None,
+    }
+}
+
+fn report(devices: Vec<DeviceSnapshot>) -> ScrapeReport {
+    ScrapeReport {
+        duration_seconds: 0.1,
+        success: true,
+        discovered_device_count: devices.len(),
+        devices,
+        command_errors: Vec::new(),
+        kernel_default_ps_max_latency_us: None,
+        self_test_schedule: Vec::new(),
+        collect_namespace: true,
+        collect_error_log: true,
+        collect_self_test: true,
+        collect_features: true,
+        collect_vendor_logs: true,
+        collect_persistent_event_log: true,
+    }
+}
+
+fn fixture_controller() -> IdentifyController {
+    IdentifyController::parse(include_bytes!("fixture/id_ctrl.bin"))
+        .expect("fixture id_ctrl should parse")
+}
+
+fn namespace_snapshot(identify: IdentifyNamespace) -> NamespaceSnapshot {
+    NamespaceSnapshot {
+        namespace: "nvme0n1".to_string(),
+        nsid: 1,
+        active: true,
+        block_device: true,
+        identify,
+        smart: None,
+        descriptors: None,
+        zoned: None,
+    }
+}
 
 #[test]
 fn fixture_replay_report_emits_expected_metrics() {
-    let identify = IdentifyController::parse(include_bytes!("fixture/id_ctrl.bin"))
-        .expect("fixture id_ctrl should parse");
+    let identify = fixture_controller();
     let namespace = IdentifyNamespace::parse(include_bytes!("fixture/id_ns.bin"))
         .expect("fixture id_ns should parse");
     let smart =
@@ -23,36 +108,26 @@ fn fixture_replay_report_emits_expected_metrics() {
     let self_test = SelfTestLogSummary::parse(include_bytes!("fixture/selftest.bin"))
         .expect("fixture selftest should parse");
 
-    let report = ScrapeReport {
-        duration_seconds: 0.42,
-        success: true,
-        discovered_device_count: 1,
-        devices: vec![DeviceSnapshot {
-            device: "nvme0".to_string(),
-            model: identify.model.clone(),
-            serial: identify.serial.clone(),
-            firmware: identify.firmware_revision.clone(),
-            accessible: true,
-            smart: Some(smart),
-            namespaces: vec![NamespaceSnapshot {
-                namespace: "nvme0n1".to_string(),
-                nsze: namespace.nsze,
-                ncap: namespace.ncap,
-                nuse: namespace.nuse,
-            }],
-            error_log: Some(ErrorLogSnapshot {
-                non_zero_entries: error.non_zero_entries,
-                max_error_count: error.max_error_count,
-            }),
-            self_test: Some(SelfTestSnapshot {
-                current_operation: self_test.current_operation,
-                current_completion_ratio: self_test.current_completion_ratio,
-            }),
-        }],
-        collect_namespace: true,
-        collect_error_log: true,
-        collect_self_test: true,
-    };
+    let mut device = device_snapshot("nvme0");
+    device.model = identify.model.clone();
+    device.serial = identify.serial.clone();
+    device.firmware = identify.firmware_revision.clone();
+    device.controller = Some(identify.clone());
+    device.smart = Some(smart);
+    device.namespaces = vec![namespace_snapshot(namespace)];
+    device.error_log = Some(ErrorLogSnapshot {
+        non_zero_entries: error.non_zero_entries,
+        max_error_count: error.max_error_count,
+        entries: error.entries.clone(),
+    });
+    device.self_test = Some(SelfTestSnapshot {
+        current_operation: self_test.current_operation,
+        current_completion_ratio: self_test.current_completion_ratio,
+        results: self_test.results.clone(),
+        failure_totals: BTreeMap::new(),
+    });
+    let mut report = report(vec![device]);
+    report.duration_seconds = 0.42;
 
     let output = encode_report(&report).expect("fixture report should encode");
 
@@ -76,27 +151,232 @@ fn fixture_replay_report_emits_expected_metrics() {
     assert!(output.contains("nvme_exporter_scrape_success 1"));
 }
 
+#[test]
+fn fixture_controller_info_and_power_states_are_exported() {
+    let identify = fixture_controller();
+    let mut device = device_snapshot("nvme0");
+    device.controller = Some(identify.clone());
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("controller report should encode");
+
+    assert!(output.contains(&format!(
+        "nvme_controller_info{{controller_id=\"0\",device=\"nvme0\",ieee_oui=\"ace42e\",lpa=\"0x1e\",oacs=\"0x0017\",oncs=\"0x00df\",subsystem_nqn=\"{}\",subsystem_vendor_id=\"0x1c5c\",vendor_id=\"0x1c5c\",version=\"1.4.0\"}} 1",
+        prometheus_escape(&identify.subsystem_nqn)
+    )));
+    assert!(output.contains("nvme_controller_max_namespaces{device=\"nvme0\"} 1"));
+    assert_eq!(identify.power_states.len(), 5);
+    assert!(output.contains("nvme_power_state_max_watts{device=\"nvme0\",state=\"0\"} 7.5"));
+    assert!(output.contains("nvme_power_state_non_operational{device=\"nvme0\",state=\"3\"} 1"));
+}
+
+#[test]
+fn current_power_state_and_queue_features_are_exported() {
+    let mut device = device_snapshot("nvme0");
+    device.controller = Some(fixture_controller());
+    device.power_state = Some(0);
+    device.features = Some(FeatureSnapshot {
+        arbitration: Some(Arbitration::parse(0x0000_0003)),
+        number_of_queues: Some(NumberOfQueues::parse(0x001F_001F)),
+        ..FeatureSnapshot::default()
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("feature report should encode");
+
+    assert!(output.contains("nvme_power_state_current{device=\"nvme0\"} 0"));
+    assert!(output.contains("nvme_feature_arbitration_burst{device=\"nvme0\"} 8"));
+    assert!(output.contains("nvme_feature_queues{device=\"nvme0\",type=\"submission\"} 32"));
+}
+
+#[test]
+fn temperature_thresholds_are_labeled_by_sensor_and_kind() {
+    let identify = fixture_controller();
+    let mut device = device_snapshot("nvme0");
+    device.controller = Some(identify.clone());
+    device.features = Some(FeatureSnapshot {
+        temperature_thresholds: vec![
+            TemperatureThresholdSnapshot {
+                sensor: 0,
+                under: false,
+                threshold: TemperatureThreshold::parse(358),
+            },
+            TemperatureThresholdSnapshot {
+                sensor: 1,
+                under: true,
+                threshold: TemperatureThreshold::parse(273),
+            },
+            TemperatureThresholdSnapshot {
+                sensor: 2,
+                under: true,
+                threshold: TemperatureThreshold::parse(0),
+            },
+        ],
+        ..FeatureSnapshot::default()
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("threshold report should encode");
+
+    assert!(output.contains(&format!(
+        "nvme_temperature_threshold_celsius{{device=\"nvme0\",kind=\"warning\",sensor=\"composite\"}} {}",
+        f64::from(identify.wctemp_kelvin) - 273.15
+    )));
+    assert!(output.contains(
+        "nvme_temperature_threshold_celsius{device=\"nvme0\",kind=\"over\",sensor=\"composite\"} 84.85"
+    ));
+    assert!(output.contains(
+        "nvme_temperature_threshold_celsius{device=\"nvme0\",kind=\"under\",sensor=\"1\"}"
+    ));
+    assert!(!output.contains(
+        "nvme_temperature_threshold_celsius{device=\"nvme0\",kind=\"under\",sensor=\"2\"}"
+    ));
+}
+
+#[test]
+fn apst_transitions_and_kernel_latency_are_exported() {
+    let mut apst_table = [0_u8; 256];
+    apst_table[0..8].copy_from_slice(&((100_u64 << 8) | (3 << 3)).to_le_bytes());
+    let apst = AutonomousPowerStateTransition::parse(1, &apst_table).expect("apst should parse");
+
+    let mut device = device_snapshot("nvme0");
+    device.controller = Some(fixture_controller());
+    device.features = Some(FeatureSnapshot {
+        apst: Some(apst),
+        ..FeatureSnapshot::default()
+    });
+    let mut report = report(vec![device]);
+    report.kernel_default_ps_max_latency_us = Some(100_000);
+
+    let output = encode_report(&report).expect("apst report should encode");
+
+    assert!(output.contains("nvme_apst_supported{device=\"nvme0\"} 1"));
+    assert!(output.contains("nvme_apst_enabled{device=\"nvme0\"} 1"));
+    assert!(output.contains("nvme_apst_transition_target_state{device=\"nvme0\",state=\"0\"} 3"));
+    assert!(output.contains("nvme_apst_transition_idle_seconds{device=\"nvme0\",state=\"0\"} 0.1"));
+    assert!(!output.contains("nvme_apst_transition_target_state{device=\"nvme0\",state=\"1\"}"));
+    assert!(output.contains("nvme_kernel_default_ps_max_latency_seconds 0.1"));
+}
+
+#[test]
+fn write_cache_and_host_memory_buffer_are_exported() {
+    let mut hmb_attributes = [0_u8; 4096];
+    hmb_attributes[0..4].copy_from_slice(&8192_u32.to_le_bytes());
+    let host_memory_buffer =
+        HostMemoryBuffer::parse(1, &hmb_attributes).expect("hmb attributes should parse");
+
+    let mut device = device_snapshot("nvme0");
+    device.controller = Some(fixture_controller());
+    device.features = Some(FeatureSnapshot {
+        volatile_write_cache: Some(VolatileWriteCache::parse(1)),
+        host_memory_buffer: Some(host_memory_buffer),
+        ..FeatureSnapshot::default()
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("write cache report should encode");
+
+    assert!(output.contains("nvme_write_cache_present{device=\"nvme0\"} 1"));
+    assert!(output.contains("nvme_write_cache_enabled{device=\"nvme0\"} 1"));
+    assert!(output.contains("nvme_host_memory_buffer_preferred_bytes{device=\"nvme0\"} 0"));
+    assert!(output.contains("nvme_host_memory_buffer_enabled{device=\"nvme0\"} 1"));
+    assert!(output.contains("nvme_host_memory_buffer_bytes{device=\"nvme0\"} 33554432"));
+}
+
+#[test]
+fn fixture_namespace_info_and_smart_are_exported() {
+    let namespace = IdentifyNamespace::parse(include_bytes!("fixture/id_ns.bin"))
+        .expect("fixture id_ns should parse");
+    let smart =
+        SmartLog::parse(include_bytes!("fixture/smart.bin")).expect("fixture smart should parse");
+
+    let mut snapshot = namespace_snapshot(namespace);
+    snapshot.smart = Some(smart);
+    snapshot.descriptors = Some(NamespaceIdentificationDescriptors {
+        uuid: Some([0x5a; 16]),
+        csi: Some(0x00),
+        ..NamespaceIdentificationDescriptors::default()
+    });
+    let mut device = device_snapshot("nvme0");
+    device.namespaces = vec![snapshot];
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("namespace report should encode");
+
+    assert!(output.contains(
+        "nvme_namespace_size_bytes{device=\"nvme0\",namespace=\"nvme0n1\"} 2000398934016"
+    ));
+    assert!(output
+        .contains("nvme_namespace_lba_size_bytes{device=\"nvme0\",namespace=\"nvme0n1\"} 512"));
+    assert!(output.contains("nvme_namespace_active{device=\"nvme0\",namespace=\"nvme0n1\"} 1"));
+    assert!(output
+        .contains("nvme_namespace_block_device_present{device=\"nvme0\",namespace=\"nvme0n1\"} 1"));
+    assert!(output.contains(
+        "nvme_namespace_info{csi=\"nvm\",device=\"nvme0\",eui64=\"ace42e002652297e\",lba_format=\"0\",metadata_extended=\"false\",metadata_size=\"0\",namespace=\"nvme0n1\",nguid=\"ace42e002652297e2ee4ac0000000001\",nsid=\"1\",protection_type=\"none\",relative_performance=\"best\",shared=\"false\",uuid=\"5a5a5a5a-5a5a-5a5a-5a5a-5a5a5a5a5a5a\"} 1"
+    ));
+    assert!(output.contains(&format!(
+        "nvme_namespace_data_units_read_total{{device=\"nvme0\",namespace=\"nvme0n1\"}} {}",
+        smart.data_units_read
+    )));
+}
+
+#[test]
+fn empty_self_test_log_has_no_last_result() {
+    let self_test = SelfTestLogSummary::parse(include_bytes!("fixture/selftest.bin"))
+        .expect("fixture selftest should parse");
+    assert!(self_test.results.is_empty());
+
+    let mut device = device_snapshot("nvme0");
+    device.self_test = Some(SelfTestSnapshot {
+        current_operation: self_test.current_operation,
+        current_completion_ratio: self_test.current_completion_ratio,
+        results: self_test.results,
+        failure_totals: BTreeMap::new(),
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("self-test report should encode");
+
+    assert!(!output.contains("nvme_self_test_last_result{device=\"nvme0\""));
+}
+
+#[test]
+fn self_test_failures_are_a_counter() {
+    let mut bytes = [0_u8; 564];
+    for index in 0..20 {
+        bytes[4 + index * 28] = 0x0F;
+    }
+    for (index, status) in [0x17_u8, 0x10, 0x25, 0x16].iter().enumerate() {
+        bytes[4 + index * 28] = *status;
+    }
+    let self_test = SelfTestLogSummary::parse(&bytes).expect("synthetic selftest should parse");
+
+    let mut device = device_snapshot("nvme0");
+    device.self_test = Some(SelfTestSnapshot {
+        current_operation: self_test.current_operation,
+        current_completion_ratio: self_test.current_completion_ratio,
+        results: self_test.results,
+        failure_totals: [("short".to_string(), 3), ("extended".to_string(), 1)]
+            .into_iter()
+            .collect(),
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("self-test report should encode");
+
+    assert!(output.contains("# TYPE nvme_self_test_failures_total counter"));
+    assert!(output.contains("nvme_self_test_failures_total{device=\"nvme0\",type=\"short\"} 3"));
+    assert!(output.contains("nvme_self_test_failures_total{device=\"nvme0\",type=\"extended\"} 1"));
+    assert!(output.contains("nvme_self_test_last_result{device=\"nvme0\",type=\"short\"} 7"));
+}
+
 #[test]
 fn stale_device_snapshot_is_marked_inaccessible() {
-    let report = ScrapeReport {
-        duration_seconds: 0.11,
-        success: false,
-        discovered_device_count: 0,
-        devices: vec![DeviceSnapshot {
-            device: "nvme9".to_string(),
-            model: "stale".to_string(),
-            serial: "stale".to_string(),
-            firmware: "stale".to_string(),
-            accessible: false,
-            smart: None,
-            namespaces: Vec::new(),
-            error_log: None,
-            self_test: None,
-        }],
-        collect_namespace: true,
-        collect_error_log: true,
-        collect_self_test: true,
-    };
+    let mut device = device_snapshot("nvme9");
+    device.accessible = false;
+    let mut report = report(vec![device]);
+    report.success = false;
+    report.discovered_device_count = 0;
 
     let output = encode_report(&report).expect("stale report should encode");
 
@@ -105,6 +385,436 @@ fn stale_device_snapshot_is_marked_inaccessible() {
     assert!(!output.contains("nvme_temperature_celsius{device=\"nvme9\"}"));
 }
 
+#[test]
+fn command_errors_are_labeled_by_opcode_and_log_page() {
+    let mut report = report(Vec::new());
+    report.command_errors = vec![
+        CommandErrorSnapshot {
+            device: "nvme0".to_string(),
+            opcode: 0x02,
+            lid: Some(0x06),
+            status: "invalid_log_page".to_string(),
+            count: 3,
+        },
+        CommandErrorSnapshot {
+            device: "nvme0".to_string(),
+            opcode: 0x06,
+            lid: None,
+            status: "invalid_field_in_command".to_string(),
+            count: 1,
+        },
+    ];
+
+    let output = encode_report(&report).expect("command error report should encode");
+
+    assert!(output.contains(
+        "nvme_exporter_command_errors_total{device=\"nvme0\",lid=\"0x06\",opcode=\"0x02\",status=\"invalid_log_page\"} 3"
+    ));
+    assert!(output.contains(
+        "nvme_exporter_command_errors_total{device=\"nvme0\",lid=\"\",opcode=\"0x06\",status=\"invalid_field_in_command\"} 1"
+    ));
+}
+
+#[test]
+fn error_log_entries_are_counted_by_status() {
+    let mut bytes = [0_u8; 1024];
+    for (index, status) in [0x0281_u16, 0x0281, 0x0002].iter().enumerate() {
+        let offset = index * 64;
+        bytes[offset..offset + 8].copy_from_slice(&(10 - index as u64).to_le_bytes());
+        bytes[offset + 12..offset + 14].copy_from_slice(&(status << 1).to_le_bytes());
+    }
+    let error = ErrorLogSummary::parse(&bytes).expect("synthetic error log should parse");
+
+    let mut device = device_snapshot("nvme0");
+    device.error_log = Some(ErrorLogSnapshot {
+        non_zero_entries: error.non_zero_entries,
+        max_error_count: error.max_error_count,
+        entries: error.entries.clone(),
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("error log report should encode");
+
+    assert!(output.contains(
+        "nvme_error_log_entry_status{device=\"nvme0\",status=\"unrecovered_read_error\"} 2"
+    ));
+    assert!(output.contains(
+        "nvme_error_log_entry_status{device=\"nvme0\",status=\"invalid_field_in_command\"} 1"
+    ));
+}
+
+#[test]
+fn firmware_slots_flag_staged_revision() {
+    let mut bytes = [0_u8; 512];
+    bytes[0] = (2 << 4) | 1;
+    bytes[8..16].copy_from_slice(b"FW100   ");
+    bytes[16..24].copy_from_slice(b"FW200   ");
+    let firmware_slots = FirmwareSlotLog::parse(&bytes).expect("firmware slot log should parse");
+
+    let mut device = device_snapshot("nvme0");
+    device.firmware = "FW100".to_string();
+    device.firmware_slots = Some(firmware_slots);
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("firmware slot report should encode");
+
+    assert!(output.contains(
+        "nvme_firmware_slot_info{active=\"true\",device=\"nvme0\",pending=\"false\",revision=\"FW100\",slot=\"1\"} 1"
+    ));
+    assert!(output.contains(
+        "nvme_firmware_slot_info{active=\"false\",device=\"nvme0\",pending=\"true\",revision=\"FW200\",slot=\"2\"} 1"
+    ));
+}
+
+#[test]
+fn zoned_namespace_reports_zones_by_state() {
+    let mut namespace_bytes = [0_u8; 4096];
+    namespace_bytes[0..8].copy_from_slice(&(1_u64 << 20).to_le_bytes());
+    namespace_bytes[128..132].copy_from_slice(&(12_u32 << 16).to_le_bytes());
+    let namespace = IdentifyNamespace::parse(&namespace_bytes).expect("namespace should parse");
+
+    let mut zoned_bytes = [0_u8; 4096];
+    zoned_bytes[4..8].copy_from_slice(&383_u32.to_le_bytes());
+    zoned_bytes[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+    zoned_bytes[2816..2824].copy_from_slice(&(1_u64 << 16).to_le_bytes());
+    let zoned = ZonedNamespace::parse(&zoned_bytes).expect("zoned namespace should parse");
+
+    let mut device = device_snapshot("nvme1");
+    device.namespaces = vec![NamespaceSnapshot {
+        namespace: "nvme1n2".to_string(),
+        nsid: 2,
+        active: true,
+        block_device: true,
+        identify: namespace,
+        smart: None,
+        descriptors: Some(NamespaceIdentificationDescriptors {
+            csi: Some(0x02),
+            ..NamespaceIdentificationDescriptors::default()
+        }),
+        zoned: Some(ZonedNamespaceSnapshot {
+            identify: zoned,
+            zones_by_state: [(ZoneState::Empty, 10), (ZoneState::Full, 6)]
+                .into_iter()
+                .collect(),
+        }),
+    }];
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("zoned report should encode");
+
+    assert!(output
+        .contains("nvme_zns_zone_size_bytes{device=\"nvme1\",namespace=\"nvme1n2\"} 268435456"));
+    assert!(output.contains("nvme_zns_zones{device=\"nvme1\",namespace=\"nvme1n2\"} 16"));
+    assert!(
+        output.contains("nvme_zns_max_active_zones{device=\"nvme1\",namespace=\"nvme1n2\"} 384")
+    );
+    assert!(!output.contains("nvme_zns_max_open_zones{"));
+    assert!(output.contains(
+        "nvme_zns_zones_by_state{device=\"nvme1\",namespace=\"nvme1n2\",state=\"full\"} 6"
+    ));
+    assert!(output.contains("csi=\"zoned\""));
+}
+
+#[test]
+fn endurance_groups_are_labeled_by_id() {
+    let mut bytes = [0_u8; 512];
+    bytes[3] = 97;
+    bytes[5] = 12;
+    bytes[32..48].copy_from_slice(&7_u128.to_le_bytes());
+    bytes[64..80].copy_from_slice(&1_000_u128.to_le_bytes());
+    bytes[80..96].copy_from_slice(&3_000_u128.to_le_bytes());
+    let log = EnduranceGroupLog::parse(&bytes).expect("endurance group log should parse");
+
+    let mut device = device_snapshot("nvme5");
+    device.endurance_groups = vec![EnduranceGroupSnapshot { id: 2, log }];
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("endurance group report should encode");
+
+    assert!(output.contains(
+        "nvme_endurance_group_percentage_used_ratio{device=\"nvme5\",endurance_group=\"2\"} 0.12"
+    ));
+    assert!(output.contains(
+        "nvme_endurance_group_available_spare_ratio{device=\"nvme5\",endurance_group=\"2\"} 0.97"
+    ));
+    assert!(output.contains(
+        "nvme_endurance_group_endurance_estimate_bytes{device=\"nvme5\",endurance_group=\"2\"} 7000000000"
+    ));
+    assert!(output.contains(
+        "nvme_endurance_group_data_units_written_total{device=\"nvme5\",endurance_group=\"2\"} 1000"
+    ));
+    assert!(output.contains(
+        "nvme_endurance_group_media_units_written_total{device=\"nvme5\",endurance_group=\"2\"} 3000"
+    ));
+}
+
+#[test]
+fn persistent_events_are_counted_by_type() {
+    let mut device = device_snapshot("nvme6");
+    device.persistent_events = Some(PersistentEventLogSnapshot {
+        total_events: 12,
+        total_log_length: 2048,
+        generation_number: 4,
+        events: Vec::new(),
+        event_totals: [
+            ("firmware_commit".to_string(), 2),
+            ("thermal_excursion".to_string(), 9),
+        ]
+        .into_iter()
+        .collect(),
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("persistent event report should encode");
+
+    assert!(output.contains("nvme_persistent_event_log_events{device=\"nvme6\"} 12"));
+    assert!(output.contains("nvme_persistent_event_log_bytes{device=\"nvme6\"} 2048"));
+    assert!(output.contains("nvme_persistent_event_log_generation{device=\"nvme6\"} 4"));
+    assert!(output
+        .contains("nvme_persistent_events_total{device=\"nvme6\",type=\"firmware_commit\"} 2"));
+    assert!(output
+        .contains("nvme_persistent_events_total{device=\"nvme6\",type=\"thermal_excursion\"} 9"));
+}
+
+#[test]
+fn controller_telemetry_availability_is_exported() {
+    let mut bytes = [0_u8; 512];
+    bytes[0] = 0x08;
+    bytes[382] = 1;
+    bytes[383] = 3;
+    let telemetry = TelemetryLogHeader::parse(&bytes).expect("telemetry header should parse");
+
+    let mut device = device_snapshot("nvme7");
+    device.telemetry = Some(telemetry);
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("telemetry report should encode");
+
+    assert!(output.contains("nvme_telemetry_controller_data_available{device=\"nvme7\"} 1"));
+    assert!(output.contains("nvme_telemetry_controller_data_generation{device=\"nvme7\"} 3"));
+}
+
+#[test]
+fn ocp_smart_log_is_exported_per_device() {
+    let mut bytes = [0_u8; 512];
+    bytes[0..16].copy_from_slice(&4_096_000_u128.to_le_bytes());
+    bytes[40..46].copy_from_slice(&[12, 0, 0, 0, 0, 0]);
+    bytes[46..48].copy_from_slice(&97_u16.to_le_bytes());
+    bytes[120] = 80;
+    bytes[128..130].copy_from_slice(&95_u16.to_le_bytes());
+    bytes[103] = 2;
+    bytes[494..496].copy_from_slice(&4_u16.to_le_bytes());
+    bytes[496..512].copy_from_slice(&[
+        0xC5, 0xAF, 0x10, 0x28, 0xEA, 0xBF, 0xF2, 0xA4, 0x9C, 0x4F, 0x6F, 0x7C, 0xC9, 0x14, 0xD5,
+        0xAF,
+    ]);
+    let ocp = OcpSmartLog::parse(&bytes).expect("ocp smart log should parse");
+
+    let mut bytes = [0_u8; 512];
+    bytes[0] = 0x01;
+    bytes[6..10].copy_from_slice(&[2, 10, 40, 200]);
+    bytes[0xF0 + 16..0xF0 + 20].copy_from_slice(&42_u32.to_le_bytes());
+    bytes[0xF0 + 0x40 + 24..0xF0 + 0x40 + 32].copy_from_slice(&5_000_u64.to_le_bytes());
+    bytes[0xF0 + 0xA0 + 6..0xF0 + 0xA0 + 8].copy_from_slice(&75_u16.to_le_bytes());
+    bytes[496..512].copy_from_slice(&[
+        0x92, 0x7A, 0xC0, 0x8C, 0xD0, 0x84, 0x6C, 0x9C, 0x70, 0x43, 0xE6, 0xD4, 0x58, 0x5E, 0xD4,
+        0x85,
+    ]);
+    let latency = OcpLatencyMonitorLog::parse(&bytes).expect("latency monitor should parse");
+
+    let mut device = device_snapshot("nvme2");
+    device.vendor_logs = Some(VendorLogSnapshot {
+        ocp_smart: Some(ocp),
+        ocp_latency_monitor: Some(latency),
+        intel_smart: None,
+        custom: Vec::new(),
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("ocp report should encode");
+
+    assert!(output.contains(
+        "nvme_ocp_info{device=\"nvme2\",log_page_version=\"4\",specification_version=\"2.0.0.0\"} 1"
+    ));
+    assert!(
+        output.contains("nvme_ocp_physical_media_written_bytes_total{device=\"nvme2\"} 4096000")
+    );
+    assert!(output.contains("nvme_ocp_bad_nand_blocks{device=\"nvme2\",type=\"system\"} 12"));
+    assert!(output.contains("nvme_ocp_free_blocks_ratio{device=\"nvme2\"} 0.8"));
+    assert!(output.contains("nvme_ocp_capacitor_health_ratio{device=\"nvme2\"} 0.95"));
+    assert!(
+        output.contains("nvme_ocp_thermal_throttling_status{device=\"nvme2\",level=\"none\"} 0")
+    );
+    assert!(output.contains("nvme_ocp_latency_monitor_enabled{device=\"nvme2\"} 1"));
+    assert!(output.contains(
+        "nvme_ocp_latency_monitor_bucket_lower_bound_seconds{bucket=\"1\",device=\"nvme2\"} 0.05"
+    ));
+    assert!(output.contains(
+        "nvme_ocp_latency_monitor_bucket_commands{bucket=\"1\",device=\"nvme2\",operation=\"read\",window=\"static\"} 42"
+    ));
+    assert!(output.contains(
+        "nvme_ocp_latency_monitor_max_latency_seconds{bucket=\"1\",device=\"nvme2\",operation=\"read\",window=\"static\"} 0.075"
+    ));
+    assert!(output.contains(
+        "nvme_ocp_latency_monitor_max_latency_timestamp_seconds{bucket=\"1\",clock=\"power_on\",device=\"nvme2\",operation=\"read\",window=\"static\"} 5"
+    ));
+}
+
+#[test]
+fn ocp_latency_monitor_buckets_are_gauges_that_can_decrease() {
+    let encode = |active: u32, fixed: u32| {
+        let mut bytes = [0_u8; 512];
+        bytes[0] = 0x01;
+        bytes[0x20 + 16..0x20 + 20].copy_from_slice(&active.to_le_bytes());
+        bytes[0xF0 + 16..0xF0 + 20].copy_from_slice(&fixed.to_le_bytes());
+        bytes[496..512].copy_from_slice(&[
+            0x92, 0x7A, 0xC0, 0x8C, 0xD0, 0x84, 0x6C, 0x9C, 0x70, 0x43, 0xE6, 0xD4, 0x58, 0x5E,
+            0xD4, 0x85,
+        ]);
+        let latency = OcpLatencyMonitorLog::parse(&bytes).expect("latency monitor should parse");
+
+        let mut device = device_snapshot("nvme2");
+        device.vendor_logs = Some(VendorLogSnapshot {
+            ocp_latency_monitor: Some(latency),
+            ..VendorLogSnapshot::default()
+        });
+        encode_report(&report(vec![device])).expect("latency report should encode")
+    };
+
+    let first = encode(30, 42);
+    let second = encode(12, 7);
+
+    assert!(first.contains("# TYPE nvme_ocp_latency_monitor_bucket_commands gauge"));
+    assert!(first.contains(
+        "nvme_ocp_latency_monitor_bucket_commands{bucket=\"1\",device=\"nvme2\",operation=\"read\",window=\"active\"} 30"
+    ));
+    assert!(second.contains(
+        "nvme_ocp_latency_monitor_bucket_commands{bucket=\"1\",device=\"nvme2\",operation=\"read\",window=\"active\"} 12"
+    ));
+    assert!(second.contains(
+        "nvme_ocp_latency_monitor_bucket_commands{bucket=\"1\",device=\"nvme2\",operation=\"read\",window=\"static\"} 7"
+    ));
+}
+
+#[test]
+fn intel_smart_log_is_labeled_with_vendor() {
+    let mut bytes = [0_u8; 512];
+    bytes[0..12].copy_from_slice(&[0xAD, 0, 0, 98, 0, 10, 0, 40, 0, 25, 0, 0]);
+    bytes[12..24].copy_from_slice(&[0xEA, 0, 0, 100, 0, 15, 7, 0, 0, 0, 0, 0]);
+    bytes[24..36].copy_from_slice(&[0xF5, 0, 0, 100, 0, 2, 0, 0, 0, 0, 0, 0]);
+    bytes[36..48].copy_from_slice(&[0xF0, 0, 0, 100, 0, 4, 0, 0, 0, 0, 0, 0]);
+    let intel = IntelSmartLog::parse(&bytes).expect("intel smart log should parse");
+
+    let mut device = device_snapshot("nvme3");
+    device.vendor_logs = Some(VendorLogSnapshot {
+        intel_smart: Some(intel),
+        ..VendorLogSnapshot::default()
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("intel report should encode");
+
+    assert!(output.contains(
+        "nvme_vendor_wear_leveling_erase_count{device=\"nvme3\",stat=\"max\",vendor=\"intel\"} 40"
+    ));
+    assert!(output
+        .contains("nvme_vendor_thermal_throttle_ratio{device=\"nvme3\",vendor=\"intel\"} 0.15"));
+    assert!(output.contains(
+        "nvme_vendor_thermal_throttle_events_total{device=\"nvme3\",vendor=\"intel\"} 7"
+    ));
+    assert!(output.contains(
+        "nvme_vendor_host_written_bytes_total{device=\"nvme3\",vendor=\"intel\"} 67108864"
+    ));
+    assert!(output
+        .contains("nvme_vendor_retry_buffer_overflows_total{device=\"nvme3\",vendor=\"intel\"} 4"));
+    assert!(output.contains(
+        "nvme_vendor_smart_normalized{attribute=\"wear_leveling\",device=\"nvme3\",vendor=\"intel\"} 98"
+    ));
+    assert!(!output.contains("nvme_vendor_pll_lock_loss_total{"));
+}
+
+#[test]
+fn custom_log_page_fields_become_metrics() {
+    let pages = parse_custom_log_pages(
+        r#"
+[[log_page]]
+name = "acme_health"
+lid = 0xD0
+length = 16
+
+[[log_page.field]]
+offset = 0
+width = 4
+metric = "nvme_custom_acme_retired_blocks"
+help = "Retired NAND blocks"
+
+[[log_page.field]]
+offset = 4
+width = 8
+scale = 512.0
+metric = "nvme_custom_acme_written_bytes_total"
+type = "counter"
+"#,
+    )
+    .expect("decoders should parse");
+    let mut bytes = [0_u8; 16];
+    bytes[0..4].copy_from_slice(&3_u32.to_le_bytes());
+    bytes[4..12].copy_from_slice(&10_u64.to_le_bytes());
+    let custom = pages[0].decode(&bytes).expect("log should decode");
+
+    let mut device = device_snapshot("nvme4");
+    device.vendor_logs = Some(VendorLogSnapshot {
+        custom: vec![custom],
+        ..VendorLogSnapshot::default()
+    });
+    let report = report(vec![device]);
+
+    let output = encode_report(&report).expect("custom report should encode");
+
+    assert!(output.contains("# HELP nvme_custom_acme_retired_blocks Retired NAND blocks"));
+    assert!(output.contains("# TYPE nvme_custom_acme_written_bytes_total counter"));
+    assert!(output
+        .contains("nvme_custom_acme_retired_blocks{device=\"nvme4\",log_page=\"acme_health\"} 3"));
+    assert!(output.contains(
+        "nvme_custom_acme_written_bytes_total{device=\"nvme4\",log_page=\"acme_health\"} 5120"
+    ));
+}
+
+#[test]
+fn custom_field_colliding_with_builtin_metric_is_skipped() {
+    let mut device = device_snapshot("nvme4");
+    device.vendor_logs = Some(VendorLogSnapshot {
+        custom: vec![CustomLogSnapshot {
+            name: "acme_health".to_string(),
+            lid: 0xD0,
+            values: vec![
+                CustomLogValue {
+                    metric: "nvme_device_accessible".to_string(),
+                    help: "Collides with a built-in metric".to_string(),
+                    kind: MetricKind::Gauge,
+                    value: 5.0,
+                },
+                CustomLogValue {
+                    metric: "nvme_custom_acme_retired_blocks".to_string(),
+                    help: "Retired NAND blocks".to_string(),
+                    kind: MetricKind::Gauge,
+                    value: 3.0,
+                },
+            ],
+        }],
+        ..VendorLogSnapshot::default()
+    });
+    let report = report(vec![device]);
+
+    let output =
+        encode_report(&report).expect("colliding custom metric should not fail the scrape");
+
+    assert!(output.contains("nvme_device_accessible{device=\"nvme4\"} 1"));
+    assert!(!output.contains("log_page=\"acme_health\"} 5"));
+    assert!(output
+        .contains("nvme_custom_acme_retired_blocks{device=\"nvme4\",log_page=\"acme_health\"} 3"));
+}
+
 fn prometheus_escape(value: &str) -> String {
     value
         .replace('\\', "\\\\")